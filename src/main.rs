@@ -1,42 +1,812 @@
-use axum::{routing::{get, post}, Json, Router};
+// RPC closures return solana_client's ClientError, which is large by design.
+#![allow(clippy::result_large_err)]
+
+use axum::{
+    extract::{ConnectInfo, MatchedPath, Path, Query, Request, State},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use axum_server::tls_rustls::RustlsConfig;
 use base64::{engine::general_purpose, Engine as _};
-use bs58;
+use bip39::{Language, Mnemonic, Seed};
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use mpl_token_metadata::{
+    accounts::Metadata,
+    instructions::{CreateMetadataAccountV3, CreateMetadataAccountV3InstructionArgs},
+    types::DataV2,
+};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
+use serde_json::json;
+use solana_account_decoder::{
+    parse_token::{TokenAccountType, UiAccountState},
+    UiAccount, UiAccountData, UiAccountEncoding,
+};
+use solana_client::{
+    client_error::{ClientError, ClientErrorKind, Result as ClientResult},
+    nonce_utils,
+    rpc_client::{GetConfirmedSignaturesForAddress2Config, RpcClient},
+    rpc_custom_error::JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY,
+    rpc_config::{
+        RpcAccountInfoConfig, RpcBlockConfig, RpcProgramAccountsConfig, RpcTransactionConfig,
+    },
+    rpc_filter::{Memcmp, RpcFilterType},
+    rpc_request::{RpcError, RpcRequest, TokenAccountsFilter},
+    rpc_response::{RpcBlockCommitment, RpcKeyedAccount},
+};
 use solana_sdk::{
-    instruction::Instruction,
-    pubkey::Pubkey,
-    signature::{Keypair, Signer},
+    account::Account,
+    address_lookup_table::{self, state::AddressLookupTable, AddressLookupTableAccount},
+    commitment_config::CommitmentConfig,
+    derivation_path::DerivationPath,
+    entrypoint::MAX_PERMITTED_DATA_INCREASE,
+    hash::Hash,
+    instruction::{AccountMeta, Instruction},
+    message::{v0, Message, VersionedMessage},
+    native_token::lamports_to_sol,
+    nonce::State as NonceState,
+    program_pack::Pack,
+    sanitize::Sanitize,
+    pubkey::{Pubkey, MAX_SEEDS, MAX_SEED_LEN},
+    secp256k1_instruction,
+    signature::{Keypair, Signature as TransactionSignature, Signer},
+    signer::keypair::keypair_from_seed_and_derivation_path,
+    stake::{
+        self,
+        instruction as stake_instruction,
+        state::{Authorized, Lockup, StakeStateV2},
+    },
     system_instruction,
+    transaction::{Transaction, TransactionError, VersionedTransaction},
+};
+use solana_transaction_status::{
+    EncodedTransactionWithStatusMeta, TransactionDetails, UiTransactionEncoding,
+};
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id, instruction as ata_instruction,
 };
 use spl_token::instruction as token_instruction;
+use spl_token_2022::extension::{
+    default_account_state::DefaultAccountState, interest_bearing_mint, memo_transfer::MemoTransfer,
+    metadata_pointer, mint_close_authority::MintCloseAuthority, transfer_fee,
+    BaseStateWithExtensions, ExtensionType, StateWithExtensionsOwned,
+};
+use spl_token_2022::state::AccountState;
 use tokio::net::TcpListener;
+use tower_http::{
+    compression::CompressionLayer,
+    cors::{AllowOrigin, Any, CorsLayer},
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
+    set_header::SetResponseHeaderLayer,
+    trace::{DefaultOnResponse, TraceLayer},
+    LatencyUnit,
+};
+use tracing::Level;
+use tracing_subscriber::EnvFilter;
+use utoipa::openapi::{
+    header::HeaderBuilder, ContentBuilder, ObjectBuilder, Ref, ResponseBuilder, Type,
+};
+use utoipa::{IntoParams, Modify, OpenApi, ToSchema};
 use axum::serve;
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 use ed25519_dalek::{Verifier, PublicKey, Signature};
-use axum::http::StatusCode;
+use sha3::Digest;
+use axum::http::{
+    header::{ACCESS_CONTROL_REQUEST_METHOD, HOST, LOCATION, ORIGIN, RETRY_AFTER},
+    uri::Authority,
+    HeaderMap, HeaderName, HeaderValue, Method, StatusCode, Uri,
+};
+
+const DEFAULT_SERVER_HOST: &str = "0.0.0.0";
+const DEFAULT_SERVER_PORT: u16 = 3000;
+const DEFAULT_MAX_BLOCK_TRANSACTIONS: usize = 100;
+const DEFAULT_HTTP_REDIRECT_PORT: u16 = 80;
+const DEFAULT_RATE_LIMIT_RPM: u32 = 60;
+const DEFAULT_RATE_LIMIT_BURST: u32 = 10;
+const X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+// Once this many clients are tracked, buckets that have refilled completely are dropped
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+const RPC_MAX_RETRIES: u32 = 3;
+const RPC_RETRY_BASE_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum SolanaNetwork {
+    MainnetBeta,
+    #[default]
+    Devnet,
+    Testnet,
+}
+
+impl SolanaNetwork {
+    fn default_rpc_url(self) -> &'static str {
+        match self {
+            SolanaNetwork::MainnetBeta => "https://api.mainnet-beta.solana.com",
+            SolanaNetwork::Devnet => "https://api.devnet.solana.com",
+            SolanaNetwork::Testnet => "https://api.testnet.solana.com",
+        }
+    }
+}
+
+fn default_server_host() -> String {
+    DEFAULT_SERVER_HOST.to_string()
+}
+
+fn default_server_port() -> u16 {
+    DEFAULT_SERVER_PORT
+}
+
+fn default_max_block_transactions() -> usize {
+    DEFAULT_MAX_BLOCK_TRANSACTIONS
+}
+
+fn default_http_redirect_port() -> u16 {
+    DEFAULT_HTTP_REDIRECT_PORT
+}
+
+fn default_rate_limit_rpm() -> u32 {
+    DEFAULT_RATE_LIMIT_RPM
+}
+
+fn default_rate_limit_burst() -> u32 {
+    DEFAULT_RATE_LIMIT_BURST
+}
+
+// Loaded from environment variables at startup; see .env.example for the full list.
+#[derive(Debug, Deserialize)]
+struct Config {
+    #[serde(default = "default_server_host")]
+    server_host: String,
+    #[serde(default = "default_server_port")]
+    server_port: u16,
+    #[serde(default)]
+    solana_network: SolanaNetwork,
+    // Falls back to the public endpoint for solana_network when unset
+    solana_rpc_url: Option<String>,
+    // Comma-separated; takes precedence over solana_rpc_url and is tried in order on failure
+    solana_rpc_urls: Option<Vec<String>>,
+    #[serde(default = "default_max_block_transactions")]
+    max_block_transactions: usize,
+    // Requests per minute allowed per client IP; 0 disables rate limiting
+    #[serde(default = "default_rate_limit_rpm")]
+    rate_limit_rpm: u32,
+    #[serde(default = "default_rate_limit_burst")]
+    rate_limit_burst: u32,
+    // Key rate limits on the leftmost X-Forwarded-For address; only safe behind a proxy that sets it
+    #[serde(default)]
+    trust_forwarded_for: bool,
+    // Comma-separated; unset or "*" allows any origin
+    allowed_origins: Option<Vec<String>>,
+    // PEM files; when both are set the server speaks HTTPS instead of plain HTTP
+    tls_cert_path: Option<String>,
+    tls_key_path: Option<String>,
+    #[serde(default)]
+    redirect_http_to_https: bool,
+    #[serde(default = "default_http_redirect_port")]
+    http_redirect_port: u16,
+}
+
+impl Config {
+    fn rpc_urls(&self) -> Vec<String> {
+        let urls: Vec<String> = self
+            .solana_rpc_urls
+            .iter()
+            .flatten()
+            .map(|url| url.trim())
+            .filter(|url| !url.is_empty())
+            .map(str::to_string)
+            .collect();
+        if !urls.is_empty() {
+            return urls;
+        }
+        vec![self
+            .solana_rpc_url
+            .clone()
+            .unwrap_or_else(|| self.solana_network.default_rpc_url().to_string())]
+    }
+
+    fn tls_paths(&self) -> Option<(&str, &str)> {
+        match (self.tls_cert_path.as_deref(), self.tls_key_path.as_deref()) {
+            (Some(cert), Some(key)) => Some((cert, key)),
+            (None, None) => None,
+            _ => panic!("TLS_CERT_PATH and TLS_KEY_PATH must be set together"),
+        }
+    }
+
+    // None means every origin is allowed
+    fn origin_allowlist(&self) -> Option<Vec<&str>> {
+        let origins: Vec<&str> = self
+            .allowed_origins
+            .iter()
+            .flatten()
+            .map(|origin| origin.trim())
+            .filter(|origin| !origin.is_empty())
+            .collect();
+        if origins.is_empty() || origins.contains(&"*") {
+            None
+        } else {
+            Some(origins)
+        }
+    }
+
+    fn cors_layer(&self) -> CorsLayer {
+        let allow_origin = match self.origin_allowlist() {
+            None => AllowOrigin::any(),
+            Some(origins) => AllowOrigin::list(
+                origins
+                    .into_iter()
+                    .map(|origin| HeaderValue::from_str(origin).expect("Invalid origin in ALLOWED_ORIGINS")),
+            ),
+        };
+        CorsLayer::new()
+            .allow_origin(allow_origin)
+            .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
+            .allow_headers(Any)
+    }
+}
+
+#[derive(Clone)]
+struct AppState {
+    rpc_pool: Arc<RpcPool>,
+    config: Arc<Config>,
+    rate_limiter: Arc<RateLimiter>,
+    // Flipped once the listener is bound; gates /ready during rolling deploys
+    ready: Arc<AtomicBool>,
+    metrics: PrometheusHandle,
+}
+
+enum RpcPoolError {
+    // Every attempt hit a transport-level failure; no node could answer
+    Exhausted(ClientError),
+    // A node answered with an error; retrying elsewhere would give the same result
+    Failed(ClientError),
+}
+
+#[derive(Serialize, ToSchema)]
+struct RpcNodeStats {
+    url: String,
+    failures: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+struct RpcPoolStats {
+    current_node: usize,
+    nodes: Vec<RpcNodeStats>,
+}
+
+// Sticks to the current node and moves round-robin to the next one whenever a call fails in transit.
+struct RpcPool {
+    clients: Vec<Arc<RpcClient>>,
+    current: AtomicUsize,
+    failures: Vec<AtomicU64>,
+}
+
+impl RpcPool {
+    fn new(urls: Vec<String>) -> Self {
+        assert!(!urls.is_empty(), "At least one RPC URL is required");
+        RpcPool {
+            failures: urls.iter().map(|_| AtomicU64::new(0)).collect(),
+            clients: urls.into_iter().map(|url| Arc::new(RpcClient::new(url))).collect(),
+            current: AtomicUsize::new(0),
+        }
+    }
+
+    fn is_transient(error: &ClientError) -> bool {
+        matches!(
+            error.kind(),
+            ClientErrorKind::Io(_)
+                | ClientErrorKind::Reqwest(_)
+                // Raised when the cluster version lookup some calls make first cannot reach the node
+                | ClientErrorKind::RpcError(RpcError::RpcRequestError(_))
+                | ClientErrorKind::RpcError(RpcError::RpcResponseError {
+                    code: JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY,
+                    ..
+                })
+        )
+    }
+
+    // Blocks between retries, so this must run on a blocking thread.
+    fn call<T>(&self, call: impl Fn(&RpcClient) -> ClientResult<T>) -> Result<T, RpcPoolError> {
+        let mut attempt = 0;
+        loop {
+            let index = self.current.load(Ordering::Relaxed) % self.clients.len();
+            let error = match call(&self.clients[index]) {
+                Ok(value) => return Ok(value),
+                Err(error) if Self::is_transient(&error) => error,
+                Err(error) => return Err(RpcPoolError::Failed(error)),
+            };
+
+            self.failures[index].fetch_add(1, Ordering::Relaxed);
+            // Only the first caller to see this node fail advances past it
+            let _ = self.current.compare_exchange(
+                index,
+                (index + 1) % self.clients.len(),
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+            tracing::warn!(node = index, attempt, error = %error, "RPC node request failed");
+
+            if attempt == RPC_MAX_RETRIES {
+                return Err(RpcPoolError::Exhausted(error));
+            }
+            let delay = RPC_RETRY_BASE_DELAY * 2u32.pow(attempt);
+            std::thread::sleep(delay + delay.mul_f64(rand::thread_rng().gen_range(0.0..0.5)));
+            attempt += 1;
+        }
+    }
+
+    fn stats(&self) -> RpcPoolStats {
+        RpcPoolStats {
+            current_node: self.current.load(Ordering::Relaxed) % self.clients.len(),
+            nodes: self
+                .clients
+                .iter()
+                .zip(&self.failures)
+                .map(|(client, failures)| RpcNodeStats {
+                    url: client.url(),
+                    failures: failures.load(Ordering::Relaxed),
+                })
+                .collect(),
+        }
+    }
+}
+
+struct TokenBucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+// Per-IP token bucket: holds up to `capacity` requests and refills at the configured rate.
+struct RateLimiter {
+    capacity: f64,
+    refill_per_second: f64,
+    buckets: Mutex<HashMap<IpAddr, TokenBucket>>,
+}
+
+impl RateLimiter {
+    fn new(requests_per_minute: u32, burst: u32) -> Self {
+        RateLimiter {
+            capacity: f64::from(burst.max(1)),
+            refill_per_second: f64::from(requests_per_minute) / 60.0,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.refill_per_second > 0.0
+    }
+
+    // Takes a token for `ip`, or returns the number of seconds until one is available.
+    fn check(&self, ip: IpAddr) -> Result<(), u64> {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+
+        if buckets.len() >= MAX_TRACKED_CLIENTS {
+            let (capacity, refill_per_second) = (self.capacity, self.refill_per_second);
+            buckets.retain(|_, bucket| {
+                bucket.tokens + now.duration_since(bucket.updated).as_secs_f64() * refill_per_second
+                    < capacity
+            });
+        }
+
+        let bucket = buckets.entry(ip).or_insert(TokenBucket {
+            tokens: self.capacity,
+            updated: now,
+        });
+        bucket.tokens = (bucket.tokens
+            + now.duration_since(bucket.updated).as_secs_f64() * self.refill_per_second)
+            .min(self.capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(((1.0 - bucket.tokens) / self.refill_per_second).ceil() as u64)
+        }
+    }
+}
+
+// CorsLayer quietly omits headers for unknown origins; preflights from them get an explicit 403 instead.
+async fn reject_unlisted_preflight(State(state): State<AppState>, request: Request, next: Next) -> Response {
+    let is_preflight = request.method() == Method::OPTIONS
+        && request.headers().contains_key(ACCESS_CONTROL_REQUEST_METHOD);
+    if let (true, Some(allowlist)) = (is_preflight, state.config.origin_allowlist()) {
+        let origin = request
+            .headers()
+            .get(ORIGIN)
+            .and_then(|value| value.to_str().ok())
+            .unwrap_or_default();
+        if !allowlist.contains(&origin) {
+            return error_response(
+                StatusCode::FORBIDDEN,
+                format!("Origin '{}' is not allowed", origin),
+            )
+            .into_response();
+        }
+    }
+
+    next.run(request).await
+}
+
+// Labels by the route template (e.g. /account/:pubkey) rather than the raw path to keep cardinality bounded.
+async fn track_metrics(request: Request, next: Next) -> Response {
+    let method = request.method().to_string();
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+    let started = Instant::now();
+
+    let response = next.run(request).await;
+
+    let status = response.status();
+    let labels = [("method", method), ("path", path)];
+    metrics::counter!("http_requests_total", &labels).increment(1);
+    metrics::histogram!("http_request_duration_seconds", &labels).record(started.elapsed().as_secs_f64());
+    if status.is_client_error() || status.is_server_error() {
+        metrics::counter!("http_errors_total", "status" => status.as_u16().to_string()).increment(1);
+    }
+
+    response
+}
+
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "server",
+    responses(
+        (status = 200, body = String, content_type = "text/plain"),
+    )
+)]
+async fn render_metrics(State(state): State<AppState>) -> String {
+    state.metrics.render()
+}
+
+// Serves the plain HTTP port when TLS is on, sending every request to the same path over HTTPS.
+async fn redirect_to_https(
+    State(https_port): State<u16>,
+    headers: HeaderMap,
+    uri: Uri,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    let host = headers
+        .get(HOST)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<Authority>().ok())
+        .ok_or_else(|| error_response(StatusCode::BAD_REQUEST, "Missing or invalid Host header"))?;
+    let authority = match https_port {
+        443 => host.host().to_string(),
+        port => format!("{}:{}", host.host(), port),
+    };
+    let path = uri.path_and_query().map(|path| path.as_str()).unwrap_or("/");
+
+    Ok((
+        StatusCode::MOVED_PERMANENTLY,
+        [(LOCATION, format!("https://{}{}", authority, path))],
+    )
+        .into_response())
+}
+
+async fn rate_limit(
+    State(state): State<AppState>,
+    ConnectInfo(client): ConnectInfo<SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !state.rate_limiter.is_enabled() {
+        return next.run(request).await;
+    }
+
+    let forwarded_ip = state
+        .config
+        .trust_forwarded_for
+        .then(|| request.headers().get(X_FORWARDED_FOR))
+        .flatten()
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(',').next())
+        .and_then(|ip| ip.trim().parse::<IpAddr>().ok());
+
+    match state.rate_limiter.check(forwarded_ip.unwrap_or(client.ip())) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            let (status, body) = error_response(
+                StatusCode::TOO_MANY_REQUESTS,
+                format!("Rate limit exceeded. Try again in {} seconds.", retry_after),
+            );
+            (status, [(RETRY_AFTER, retry_after.to_string())], body).into_response()
+        }
+    }
+}
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 struct SuccessResponse<T> {
     success: bool,
     data: T,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 struct ErrorResponse {
     success: bool,
     error: String,
 }
 
-#[derive(Serialize)]
+// Logged inside the request span, so the message is tied to the request ID.
+fn error_response(status: StatusCode, error: impl Into<String>) -> (StatusCode, Json<ErrorResponse>) {
+    let error = error.into();
+    if status.is_server_error() {
+        tracing::error!(status = status.as_u16(), error = %error, "request failed");
+    } else {
+        tracing::warn!(status = status.as_u16(), error = %error, "request rejected");
+    }
+
+    (
+        status,
+        Json(ErrorResponse {
+            success: false,
+            error,
+        }),
+    )
+}
+
+fn parse_pubkey(value: &str, field: &str) -> Result<Pubkey, (StatusCode, Json<ErrorResponse>)> {
+    Pubkey::from_str(value).map_err(|_| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Invalid {} public key", field),
+        )
+    })
+}
+
+// Runs a blocking RPC call off the async runtime through the node pool, leaving the
+// pool error for callers that need to map specific RPC error codes themselves.
+async fn rpc_pool_call<T, F>(
+    state: &AppState,
+    call: F,
+) -> Result<Result<T, RpcPoolError>, (StatusCode, Json<ErrorResponse>)>
+where
+    T: Send + 'static,
+    F: Fn(&RpcClient) -> ClientResult<T> + Send + 'static,
+{
+    let rpc_pool = state.rpc_pool.clone();
+    let started = Instant::now();
+    let result = tokio::task::spawn_blocking(move || rpc_pool.call(call)).await;
+    metrics::histogram!("rpc_request_duration_seconds").record(started.elapsed().as_secs_f64());
+
+    result.map_err(|e| {
+        error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("RPC task failed: {}", e),
+        )
+    })
+}
+
+// Errors returned by a node map to 502; running out of reachable nodes maps to 503.
+fn rpc_error_response(error: RpcPoolError) -> (StatusCode, Json<ErrorResponse>) {
+    match error {
+        RpcPoolError::Failed(e) => {
+            error_response(StatusCode::BAD_GATEWAY, format!("RPC request failed: {}", e))
+        }
+        RpcPoolError::Exhausted(e) => error_response(
+            StatusCode::SERVICE_UNAVAILABLE,
+            format!("All RPC nodes failed: {}", e),
+        ),
+    }
+}
+
+async fn rpc_call<T, F>(state: &AppState, call: F) -> Result<T, (StatusCode, Json<ErrorResponse>)>
+where
+    T: Send + 'static,
+    F: Fn(&RpcClient) -> ClientResult<T> + Send + 'static,
+{
+    rpc_pool_call(state, call).await?.map_err(rpc_error_response)
+}
+
+async fn fetch_account(state: &AppState, pubkey: Pubkey) -> Result<Account, (StatusCode, Json<ErrorResponse>)> {
+    rpc_call(state, move |rpc| {
+        rpc.get_account_with_commitment(&pubkey, rpc.commitment())
+    })
+    .await?
+    .value
+    .ok_or_else(|| error_response(StatusCode::NOT_FOUND, "Account not found"))
+}
+
+fn parse_commitment(commitment: Option<&str>) -> Result<Option<CommitmentConfig>, (StatusCode, Json<ErrorResponse>)> {
+    match commitment {
+        None => Ok(None),
+        Some("processed") => Ok(Some(CommitmentConfig::processed())),
+        Some("confirmed") => Ok(Some(CommitmentConfig::confirmed())),
+        Some("finalized") => Ok(Some(CommitmentConfig::finalized())),
+        Some(other) => Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Invalid commitment '{}'; expected processed, confirmed or finalized",
+                other
+            ),
+        )),
+    }
+}
+
+// Token 2022's base account layout matches classic SPL Token, so both decode through it.
+fn unpack_token_account(account: Account) -> Result<spl_token_2022::state::Account, (StatusCode, Json<ErrorResponse>)> {
+    if account.owner != spl_token::ID && account.owner != spl_token_2022::ID {
+        return Err(error_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Account is not owned by a token program",
+        ));
+    }
+
+    StateWithExtensionsOwned::<spl_token_2022::state::Account>::unpack(account.data)
+        .map(|state| state.base)
+        .map_err(|_| error_response(StatusCode::UNPROCESSABLE_ENTITY, "Account is not a valid token account"))
+}
+
+// Block and signature history queries reject `processed`, so catch it before it reaches the node.
+fn parse_confirmed_commitment(commitment: Option<&str>) -> Result<Option<CommitmentConfig>, (StatusCode, Json<ErrorResponse>)> {
+    let commitment = parse_commitment(commitment)?;
+    if commitment == Some(CommitmentConfig::processed()) {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "This query only supports confirmed or finalized commitment",
+        ));
+    }
+    Ok(commitment)
+}
+
+// Classic mints carry no extensions, so they unpack as a Token 2022 mint with an empty TLV area.
+fn unpack_mint(account: Account) -> Result<StateWithExtensionsOwned<spl_token_2022::state::Mint>, (StatusCode, Json<ErrorResponse>)> {
+    if account.owner != spl_token::ID && account.owner != spl_token_2022::ID {
+        return Err(error_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Account is not owned by a token program",
+        ));
+    }
+
+    StateWithExtensionsOwned::<spl_token_2022::state::Mint>::unpack(account.data)
+        .map_err(|_| error_response(StatusCode::UNPROCESSABLE_ENTITY, "Account is not a valid SPL mint"))
+}
+
+fn parse_keypair(secret: &str) -> Result<Keypair, (StatusCode, Json<ErrorResponse>)> {
+    let secret_bytes = bs58::decode(secret)
+        .into_vec()
+        .map_err(|_| error_response(StatusCode::BAD_REQUEST, "Invalid secret key"))?;
+    Keypair::from_bytes(&secret_bytes)
+        .map_err(|_| error_response(StatusCode::BAD_REQUEST, "Invalid keypair format"))
+}
+
+fn parse_blockhash(value: &str) -> Result<Hash, (StatusCode, Json<ErrorResponse>)> {
+    Hash::from_str(value).map_err(|_| error_response(StatusCode::BAD_REQUEST, "Invalid recent_blockhash"))
+}
+
+fn decode_transaction(encoded: &str) -> Result<Transaction, (StatusCode, Json<ErrorResponse>)> {
+    let wire_bytes = general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|_| error_response(StatusCode::BAD_REQUEST, "Invalid base64 transaction"))?;
+    bincode::deserialize(&wire_bytes).map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to deserialize transaction: {}", e),
+        )
+    })
+}
+
+#[derive(Serialize, ToSchema)]
 struct KeypairData {
     pubkey: String,
     secret: String,
 }
 
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Serialize, ToSchema)]
+struct HealthResponse {
+    status: &'static str,
+    rpc: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    latency_ms: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pool: Option<RpcPoolStats>,
+}
+
+impl HealthResponse {
+    fn degraded(status: &'static str, rpc: &'static str, error: impl Into<String>) -> (StatusCode, Json<HealthResponse>) {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(HealthResponse {
+                status,
+                rpc,
+                latency_ms: None,
+                error: Some(error.into()),
+                pool: None,
+            }),
+        )
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "server",
+    responses(
+        (status = 200, body = HealthResponse),
+        (status = 503, description = "RPC node unreachable or server still starting", body = HealthResponse),
+    )
+)]
+async fn health(State(state): State<AppState>) -> (StatusCode, Json<HealthResponse>) {
+    let rpc_pool = state.rpc_pool.clone();
+    let started = Instant::now();
+    let check = tokio::task::spawn_blocking(move || rpc_pool.call(|rpc| rpc.get_health()));
+
+    let (status, mut response) = match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, check).await {
+        Ok(Ok(Ok(()))) => (
+            StatusCode::OK,
+            Json(HealthResponse {
+                status: "ok",
+                rpc: "ok",
+                latency_ms: Some(started.elapsed().as_millis() as u64),
+                error: None,
+                pool: None,
+            }),
+        ),
+        Ok(Ok(Err(RpcPoolError::Failed(e) | RpcPoolError::Exhausted(e)))) => {
+            HealthResponse::degraded("degraded", "unreachable", e.to_string())
+        }
+        Ok(Err(e)) => HealthResponse::degraded("degraded", "unreachable", format!("RPC task failed: {}", e)),
+        Err(_) => HealthResponse::degraded(
+            "degraded",
+            "unreachable",
+            format!("RPC health check timed out after {} seconds", HEALTH_CHECK_TIMEOUT.as_secs()),
+        ),
+    };
+    response.pool = Some(state.rpc_pool.stats());
+    (status, response)
+}
+
+#[utoipa::path(
+    get,
+    path = "/ready",
+    tag = "server",
+    responses(
+        (status = 200, body = HealthResponse),
+        (status = 503, description = "RPC node unreachable or server still starting", body = HealthResponse),
+    )
+)]
+async fn ready(state: State<AppState>) -> (StatusCode, Json<HealthResponse>) {
+    if !state.ready.load(Ordering::Acquire) {
+        return HealthResponse::degraded("starting", "unknown", "Server is still initializing");
+    }
+    health(state).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/",
+    tag = "server",
+    responses(
+        (status = 200, body = String, content_type = "text/plain"),
+    )
+)]
 async fn root() -> &'static str {
     "✅ Solana Rust Server is running!"
 }
 
+#[utoipa::path(
+    post,
+    path = "/keypair",
+    tag = "keypair",
+    responses(
+        (status = 200, body = SuccessResponse<KeypairData>),
+    )
+)]
 async fn generate_keypair() -> Json<SuccessResponse<KeypairData>> {
     let keypair = Keypair::new();
     Json(SuccessResponse {
@@ -48,99 +818,150 @@ async fn generate_keypair() -> Json<SuccessResponse<KeypairData>> {
     })
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Default, Clone, Copy, ToSchema)]
+#[serde(rename_all = "lowercase")]
+enum SignatureEncoding {
+    #[default]
+    Base64,
+    Base58,
+}
+
+impl SignatureEncoding {
+    fn encode(self, bytes: &[u8]) -> String {
+        match self {
+            SignatureEncoding::Base64 => general_purpose::STANDARD.encode(bytes),
+            SignatureEncoding::Base58 => bs58::encode(bytes).into_string(),
+        }
+    }
+
+    fn decode(self, value: &str) -> Option<Vec<u8>> {
+        match self {
+            SignatureEncoding::Base64 => general_purpose::STANDARD.decode(value).ok(),
+            SignatureEncoding::Base58 => bs58::decode(value).into_vec().ok(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            SignatureEncoding::Base64 => "base64",
+            SignatureEncoding::Base58 => "base58",
+        }
+    }
+
+    // For batch items, where an unknown encoding is reported per item instead of failing the request
+    fn parse(value: Option<&str>) -> Result<Self, String> {
+        match value {
+            None | Some("base64") => Ok(SignatureEncoding::Base64),
+            Some("base58") => Ok(SignatureEncoding::Base58),
+            Some(other) => Err(format!(
+                "Invalid encoding '{}'; expected base64 or base58",
+                other
+            )),
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
 struct SignMessageRequest {
     message: String,
     secret: String,
+    #[serde(default)]
+    encoding: SignatureEncoding,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 struct SignedMessage {
     signature: String,
     public_key: String,
     message: String,
 }
 
-async fn sign_message(Json(payload): Json<SignMessageRequest>) -> Json<Result<SuccessResponse<SignedMessage>, ErrorResponse>> {
-    let secret_bytes = match bs58::decode(&payload.secret).into_vec() {
-        Ok(bytes) => bytes,
-        Err(_) => {
-            return Json(Err(ErrorResponse {
-                success: false,
-                error: "Invalid secret key".into(),
-            }))
-        }
-    };
-
-    let keypair = match Keypair::from_bytes(&secret_bytes) {
-        Ok(kp) => kp,
-        Err(_) => {
-            return Json(Err(ErrorResponse {
-                success: false,
-                error: "Invalid keypair format".into(),
-            }))
-        }
-    };
+#[utoipa::path(
+    post,
+    path = "/message/sign",
+    tag = "message",
+    request_body = SignMessageRequest,
+    responses(
+        (status = 200, body = SuccessResponse<SignedMessage>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn sign_message(
+    Json(payload): Json<SignMessageRequest>,
+) -> Result<Json<SuccessResponse<SignedMessage>>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = parse_keypair(&payload.secret)?;
 
     let signature = keypair.sign_message(payload.message.as_bytes());
 
-    Json(Ok(SuccessResponse {
+    Ok(Json(SuccessResponse {
         success: true,
         data: SignedMessage {
-            signature: general_purpose::STANDARD.encode(signature.as_ref()),
+            signature: payload.encoding.encode(signature.as_ref()),
             public_key: keypair.pubkey().to_string(),
             message: payload.message,
         },
     }))
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 struct VerifyMessageRequest {
     message: String,
     signature: String,
     pubkey: String,
+    #[serde(default)]
+    encoding: SignatureEncoding,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 struct VerifyMessageResponse {
     valid: bool,
     message: String,
     pubkey: String,
 }
 
-async fn verify_message(
-    Json(payload): Json<VerifyMessageRequest>
-    ) -> Json<Result<SuccessResponse<VerifyMessageResponse>, ErrorResponse>> {
+// Shared by /message/verify and /message/verify-batch; the error is a client-facing message.
+fn verify_ed25519(message: &str, signature: &str, pubkey: &str, encoding: SignatureEncoding) -> Result<bool, String> {
     // Decode public key from base58
-    let pubkey_bytes = match bs58::decode(&payload.pubkey).into_vec() {
+    let pubkey_bytes = match bs58::decode(pubkey).into_vec() {
         Ok(bytes) if bytes.len() == 32 => bytes,
-        _ => {
-            return Json(Err(ErrorResponse {
-                success: false,
-                error: "Invalid base58 pubkey or incorrect size".into(),
-            }));
-        }
+        _ => return Err("Invalid base58 pubkey or incorrect size".into()),
     };
 
-    // Decode signature from base64
-    let signature_bytes = match general_purpose::STANDARD.decode(&payload.signature) {
-        Ok(bytes) if bytes.len() == 64 => bytes,
+    // Decode signature using the requested encoding (base64 by default)
+    let signature_bytes = match encoding.decode(signature) {
+        Some(bytes) if bytes.len() == 64 => bytes,
         _ => {
-            return Json(Err(ErrorResponse {
-                success: false,
-                error: "Invalid base64 signature or incorrect size".into(),
-            }));
+            return Err(format!(
+                "Invalid {} signature or incorrect size",
+                encoding.name()
+            ))
         }
     };
 
     // Convert to PublicKey and Signature
-    let pubkey = PublicKey::from_bytes(&pubkey_bytes).unwrap();
-    let signature = Signature::from_bytes(&signature_bytes).unwrap();
+    let pubkey = PublicKey::from_bytes(&pubkey_bytes).map_err(|_| "Invalid ed25519 public key".to_string())?;
+    let signature = Signature::from_bytes(&signature_bytes).map_err(|_| "Invalid ed25519 signature".to_string())?;
+
+    Ok(pubkey.verify(message.as_bytes(), &signature).is_ok())
+}
 
-    // Verify
-    let is_valid = pubkey.verify(payload.message.as_bytes(), &signature).is_ok();
+#[utoipa::path(
+    post,
+    path = "/message/verify",
+    tag = "message",
+    request_body = VerifyMessageRequest,
+    responses(
+        (status = 200, body = SuccessResponse<VerifyMessageResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn verify_message(
+    Json(payload): Json<VerifyMessageRequest>,
+) -> Result<Json<SuccessResponse<VerifyMessageResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let is_valid = verify_ed25519(&payload.message, &payload.signature, &payload.pubkey, payload.encoding)
+        .map_err(|error| error_response(StatusCode::BAD_REQUEST, error))?;
 
-    Json(Ok(SuccessResponse {
+    Ok(Json(SuccessResponse {
         success: true,
         data: VerifyMessageResponse {
             valid: is_valid,
@@ -152,18 +973,21 @@ async fn verify_message(
 
 
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 struct CreateTokenRequest {
     mint: String,
 
     #[serde(rename = "mintAuthority")]
     mint_authority: String,
 
+    #[serde(rename = "freezeAuthority", alias = "freeze_authority")]
+    freeze_authority: Option<String>,
+
     decimals: u8,
 }
 
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 struct AccountMetaResponse {
     pubkey: String,
     is_signer: bool,
@@ -172,53 +996,50 @@ struct AccountMetaResponse {
 
 
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
 struct InstructionData {
     program_id: String,
     accounts: Vec<AccountMetaResponse>,
     instruction_data: String,
 }
 
+#[utoipa::path(
+    post,
+    path = "/token/create",
+    tag = "token",
+    request_body = CreateTokenRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
 async fn create_token(
     Json(payload): Json<CreateTokenRequest>,
 ) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
     // Parse mint pubkey
-    let mint_pubkey = Pubkey::from_str(&payload.mint).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                success: false,
-                error: "Invalid mint public key".to_string(),
-            }),
-        )
-    })?;
+    let mint_pubkey = parse_pubkey(&payload.mint, "mint")?;
 
     // Parse mint authority pubkey
-    let mint_authority_pubkey = Pubkey::from_str(&payload.mint_authority).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                success: false,
-                error: "Invalid mintAuthority public key".to_string(),
-            }),
-        )
-    })?;
+    let mint_authority_pubkey = parse_pubkey(&payload.mint_authority, "mintAuthority")?;
+
+    let freeze_authority_pubkey = payload
+        .freeze_authority
+        .as_deref()
+        .map(|value| parse_pubkey(value, "freezeAuthority"))
+        .transpose()?;
 
     // Generate the instruction
     let ix: Instruction = token_instruction::initialize_mint(
         &spl_token::ID,
         &mint_pubkey,
         &mint_authority_pubkey,
-        None,
+        freeze_authority_pubkey.as_ref(),
         payload.decimals,
     )
     .map_err(|e| {
-        (
+        error_response(
             StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                success: false,
-                error: format!("Failed to create instruction: {}", e),
-            }),
+            format!("Failed to create instruction: {}", e),
         )
     })?;
 
@@ -249,7 +1070,70 @@ async fn create_token(
     }))
 }
 
-#[derive(Deserialize)]
+fn instruction_data(ix: Instruction) -> InstructionData {
+    InstructionData {
+        program_id: ix.program_id.to_string(),
+        accounts: ix
+            .accounts
+            .into_iter()
+            .map(|meta| AccountMetaResponse {
+                pubkey: meta.pubkey.to_string(),
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        instruction_data: general_purpose::STANDARD.encode(&ix.data),
+    }
+}
+
+// Inverse of `instruction_data`, used when callers hand instructions back to be assembled.
+fn parse_instruction(index: usize, data: &InstructionData) -> Result<Instruction, (StatusCode, Json<ErrorResponse>)> {
+    let program_id = parse_pubkey(&data.program_id, &format!("instructions[{}].program_id", index))?;
+    let accounts = data
+        .accounts
+        .iter()
+        .map(|meta| {
+            Ok(AccountMeta {
+                pubkey: parse_pubkey(&meta.pubkey, &format!("instructions[{}] account", index))?,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let data = general_purpose::STANDARD
+        .decode(&data.instruction_data)
+        .map_err(|_| {
+            error_response(
+                StatusCode::BAD_REQUEST,
+                format!("Invalid base64 instruction_data in instructions[{}]", index),
+            )
+        })?;
+
+    Ok(Instruction {
+        program_id,
+        accounts,
+        data,
+    })
+}
+
+// Accepts either the classic SPL Token program or Token 2022, defaulting to classic.
+fn parse_token_program(program_id: Option<&str>) -> Result<Pubkey, (StatusCode, Json<ErrorResponse>)> {
+    let program_id = match program_id {
+        Some(value) => parse_pubkey(value, "program_id")?,
+        None => return Ok(spl_token::ID),
+    };
+
+    if program_id != spl_token::ID && program_id != spl_token_2022::ID {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "program_id must be the SPL Token or Token 2022 program",
+        ));
+    }
+
+    Ok(program_id)
+}
+
+#[derive(Deserialize, ToSchema)]
 struct MintTokenRequest {
     mint: String,
     destination: String,
@@ -258,46 +1142,32 @@ struct MintTokenRequest {
 }
 
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 struct InstructionResponse {
     program_id: String,
     accounts: Vec<AccountMetaResponse>,
     instruction_data: String,
 }
 
+#[utoipa::path(
+    post,
+    path = "/token/mint",
+    tag = "token",
+    request_body = MintTokenRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
 async fn mint_token(
     Json(payload): Json<MintTokenRequest>,
 ) -> Result<Json<SuccessResponse<InstructionResponse>>, (StatusCode, Json<ErrorResponse>)> {
     // Parse pubkeys
-    let mint = Pubkey::from_str(&payload.mint).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                success: false,
-                error: "Invalid mint public key".to_string(),
-            }),
-        )
-    })?;
+    let mint = parse_pubkey(&payload.mint, "mint")?;
 
-    let destination = Pubkey::from_str(&payload.destination).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                success: false,
-                error: "Invalid destination public key".to_string(),
-            }),
-        )
-    })?;
+    let destination = parse_pubkey(&payload.destination, "destination")?;
 
-    let authority = Pubkey::from_str(&payload.authority).map_err(|_| {
-        (
-            StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                success: false,
-                error: "Invalid authority public key".to_string(),
-            }),
-        )
-    })?;
+    let authority = parse_pubkey(&payload.authority, "authority")?;
 
     // Build the instruction
     let ix: Instruction = token_instruction::mint_to(
@@ -309,12 +1179,9 @@ async fn mint_token(
         payload.amount,
     )
     .map_err(|e| {
-        (
+        error_response(
             StatusCode::BAD_REQUEST,
-            Json(ErrorResponse {
-                success: false,
-                error: format!("Failed to create instruction: {}", e),
-            }),
+            format!("Failed to create instruction: {}", e),
         )
     })?;
 
@@ -345,27 +1212,51 @@ async fn mint_token(
     }))
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 struct SendSolRequest {
     from: String,
     to: String,
     lamports: u64,
 }
 
-async fn send_sol(Json(payload): Json<SendSolRequest>) -> Json<SuccessResponse<Instruction>> {
-    let ix = system_instruction::transfer(
-        &Pubkey::from_str(&payload.from).unwrap(),
-        &Pubkey::from_str(&payload.to).unwrap(),
-        payload.lamports,
-    );
+#[utoipa::path(
+    post,
+    path = "/send/sol",
+    tag = "system",
+    request_body = SendSolRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn send_sol(
+    Json(payload): Json<SendSolRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let from = parse_pubkey(&payload.from, "from")?;
+    let to = parse_pubkey(&payload.to, "to")?;
 
-    Json(SuccessResponse {
+    if payload.lamports == 0 {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "lamports must be greater than 0",
+        ));
+    }
+    if from == to {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "from and to must be different accounts",
+        ));
+    }
+
+    let ix = system_instruction::transfer(&from, &to, payload.lamports);
+
+    Ok(Json(SuccessResponse {
         success: true,
-        data: ix,
-    })
+        data: instruction_data(ix),
+    }))
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, ToSchema)]
 struct SendTokenRequest {
     destination: String,
     mint: String,
@@ -373,25 +1264,4893 @@ struct SendTokenRequest {
     amount: u64,
 }
 
-async fn send_token(Json(payload): Json<SendTokenRequest>) -> Json<SuccessResponse<Instruction>> {
-    let ix = token_instruction::transfer(
-        &spl_token::ID,
-        &Pubkey::from_str(&payload.mint).unwrap(),
-        &Pubkey::from_str(&payload.destination).unwrap(),
-        &Pubkey::from_str(&payload.owner).unwrap(),
-        &[],
-        payload.amount,
-    ).unwrap();
-
-    Json(SuccessResponse {
+// `/send/token` returns the instruction in solana's own serde form, with keys and data as byte arrays.
+#[derive(Serialize, ToSchema)]
+struct RawAccountMeta {
+    pubkey: Vec<u8>,
+    is_signer: bool,
+    is_writable: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+struct RawInstruction {
+    program_id: Vec<u8>,
+    accounts: Vec<RawAccountMeta>,
+    data: Vec<u8>,
+}
+
+impl From<Instruction> for RawInstruction {
+    fn from(ix: Instruction) -> Self {
+        RawInstruction {
+            program_id: ix.program_id.to_bytes().to_vec(),
+            accounts: ix
+                .accounts
+                .into_iter()
+                .map(|meta| RawAccountMeta {
+                    pubkey: meta.pubkey.to_bytes().to_vec(),
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: ix.data,
+        }
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/send/token",
+    tag = "system",
+    request_body = SendTokenRequest,
+    responses(
+        (status = 200, body = SuccessResponse<RawInstruction>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn send_token(
+    Json(payload): Json<SendTokenRequest>,
+) -> Result<Json<SuccessResponse<RawInstruction>>, (StatusCode, Json<ErrorResponse>)> {
+    let mint = parse_pubkey(&payload.mint, "mint")?;
+    let destination = parse_pubkey(&payload.destination, "destination")?;
+    let owner = parse_pubkey(&payload.owner, "owner")?;
+
+    if payload.amount == 0 {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "amount must be greater than 0",
+        ));
+    }
+
+    let ix = token_instruction::transfer(
+        &spl_token::ID,
+        &mint,
+        &destination,
+        &owner,
+        &[],
+        payload.amount,
+    )
+    .map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to create instruction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: ix.into(),
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct InitializeAccount3Request {
+    account: String,
+    mint: String,
+    owner: String,
+    program_id: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct InitializeAccount3Response {
+    #[serde(flatten)]
+    instruction: InstructionData,
+    note: &'static str,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/initialize-account3",
+    tag = "token",
+    request_body = InitializeAccount3Request,
+    responses(
+        (status = 200, body = SuccessResponse<InitializeAccount3Response>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn initialize_account3(
+    Json(payload): Json<InitializeAccount3Request>,
+) -> Result<Json<SuccessResponse<InitializeAccount3Response>>, (StatusCode, Json<ErrorResponse>)> {
+    let program_id = parse_token_program(payload.program_id.as_deref())?;
+    let account = parse_pubkey(&payload.account, "account")?;
+    let mint = parse_pubkey(&payload.mint, "mint")?;
+    let owner = parse_pubkey(&payload.owner, "owner")?;
+
+    let ix = if program_id == spl_token_2022::ID {
+        spl_token_2022::instruction::initialize_account3(&program_id, &account, &mint, &owner)
+    } else {
+        token_instruction::initialize_account3(&program_id, &account, &mint, &owner)
+    }
+    .map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to create instruction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: InitializeAccount3Response {
+            instruction: instruction_data(ix),
+            note: "initialize_account3 does not take the rent sysvar account (unlike initialize_account) and is the recommended initialization path for new integrations",
+        },
+    }))
+}
+
+const MAX_TRANSFERS_PER_BATCH: usize = 50;
+
+#[derive(Deserialize, ToSchema)]
+struct SolTransferEntry {
+    to: String,
+    lamports: u64,
+}
+
+#[derive(Deserialize, ToSchema)]
+struct TransferManyRequest {
+    from: String,
+    transfers: Vec<SolTransferEntry>,
+    limit: Option<usize>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct BatchEntryError {
+    index: usize,
+    error: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct TransferManyResponse {
+    instructions: Vec<InstructionData>,
+    errors: Vec<BatchEntryError>,
+    total_lamports: u64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/system/transfer-many",
+    tag = "system",
+    request_body = TransferManyRequest,
+    responses(
+        (status = 200, body = SuccessResponse<TransferManyResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn transfer_many(
+    Json(payload): Json<TransferManyRequest>,
+) -> Result<Json<SuccessResponse<TransferManyResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let from = parse_pubkey(&payload.from, "from")?;
+
+    let limit = payload
+        .limit
+        .unwrap_or(MAX_TRANSFERS_PER_BATCH)
+        .min(MAX_TRANSFERS_PER_BATCH);
+    if payload.transfers.len() > limit {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Too many transfers; maximum is {}", limit),
+        ));
+    }
+
+    let mut instructions = Vec::new();
+    let mut errors = Vec::new();
+    let mut total_lamports: u64 = 0;
+
+    for (index, transfer) in payload.transfers.iter().enumerate() {
+        let to = match Pubkey::from_str(&transfer.to) {
+            Ok(pubkey) => pubkey,
+            Err(_) => {
+                errors.push(BatchEntryError {
+                    index,
+                    error: "Invalid to public key".into(),
+                });
+                continue;
+            }
+        };
+
+        if transfer.lamports == 0 {
+            errors.push(BatchEntryError {
+                index,
+                error: "lamports must be greater than 0".into(),
+            });
+            continue;
+        }
+
+        total_lamports = total_lamports.checked_add(transfer.lamports).ok_or_else(|| {
+            error_response(StatusCode::BAD_REQUEST, "Total lamports overflows u64")
+        })?;
+        instructions.push(instruction_data(system_instruction::transfer(
+            &from,
+            &to,
+            transfer.lamports,
+        )));
+    }
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: TransferManyResponse {
+            instructions,
+            errors,
+            total_lamports,
+        },
+    }))
+}
+
+const MAX_TOKEN_TRANSFERS_PER_BATCH: usize = 20;
+
+#[derive(Deserialize, ToSchema)]
+struct TokenTransferEntry {
+    destination: String,
+    amount: u64,
+}
+
+#[derive(Deserialize, ToSchema)]
+struct TokenTransferManyRequest {
+    source: String,
+    mint: String,
+    owner: String,
+    decimals: u8,
+    transfers: Vec<TokenTransferEntry>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct TokenTransferManyResponse {
+    instructions: Vec<InstructionData>,
+    errors: Vec<BatchEntryError>,
+    total_amount: u64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/transfer-many",
+    tag = "token",
+    request_body = TokenTransferManyRequest,
+    responses(
+        (status = 200, body = SuccessResponse<TokenTransferManyResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn token_transfer_many(
+    Json(payload): Json<TokenTransferManyRequest>,
+) -> Result<Json<SuccessResponse<TokenTransferManyResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let source = parse_pubkey(&payload.source, "source")?;
+    let mint = parse_pubkey(&payload.mint, "mint")?;
+    let owner = parse_pubkey(&payload.owner, "owner")?;
+
+    if payload.transfers.len() > MAX_TOKEN_TRANSFERS_PER_BATCH {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Too many transfers; maximum is {}",
+                MAX_TOKEN_TRANSFERS_PER_BATCH
+            ),
+        ));
+    }
+
+    let mut instructions = Vec::new();
+    let mut errors = Vec::new();
+    let mut total_amount: u64 = 0;
+
+    for (index, transfer) in payload.transfers.iter().enumerate() {
+        let destination = match Pubkey::from_str(&transfer.destination) {
+            Ok(pubkey) => pubkey,
+            Err(_) => {
+                errors.push(BatchEntryError {
+                    index,
+                    error: "Invalid destination public key".into(),
+                });
+                continue;
+            }
+        };
+
+        match token_instruction::transfer_checked(
+            &spl_token::ID,
+            &source,
+            &mint,
+            &destination,
+            &owner,
+            &[],
+            transfer.amount,
+            payload.decimals,
+        ) {
+            Ok(ix) => {
+                total_amount = total_amount.checked_add(transfer.amount).ok_or_else(|| {
+                    error_response(StatusCode::BAD_REQUEST, "Total amount overflows u64")
+                })?;
+                instructions.push(instruction_data(ix));
+            }
+            Err(e) => errors.push(BatchEntryError {
+                index,
+                error: format!("Failed to create instruction: {}", e),
+            }),
+        }
+    }
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: TokenTransferManyResponse {
+            instructions,
+            errors,
+            total_amount,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct AccountDataSizeRequest {
+    account: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct AccountDataSizeResponse {
+    account: String,
+    data_length: usize,
+    max_realloc_delta: usize,
+    can_realloc: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/get-account-data-size",
+    tag = "rpc",
+    request_body = AccountDataSizeRequest,
+    responses(
+        (status = 200, body = SuccessResponse<AccountDataSizeResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_account_data_size(
+    State(state): State<AppState>,
+    Json(payload): Json<AccountDataSizeRequest>,
+) -> Result<Json<SuccessResponse<AccountDataSizeResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let pubkey = parse_pubkey(&payload.account, "account")?;
+    let account = fetch_account(&state, pubkey).await?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: AccountDataSizeResponse {
+            account: payload.account,
+            data_length: account.data.len(),
+            // Per-instruction realloc limit; executable accounts cannot be resized at all
+            max_realloc_delta: MAX_PERMITTED_DATA_INCREASE,
+            can_realloc: !account.executable,
+        },
+    }))
+}
+
+fn well_known_addresses() -> &'static HashMap<&'static str, &'static str> {
+    static ADDRESSES: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    ADDRESSES.get_or_init(|| {
+        HashMap::from([
+            ("11111111111111111111111111111111", "System Program"),
+            ("ComputeBudget111111111111111111111111111111", "Compute Budget Program"),
+            ("Stake11111111111111111111111111111111111111", "Stake Program"),
+            ("Vote111111111111111111111111111111111111111", "Vote Program"),
+            ("Config1111111111111111111111111111111111111", "Config Program"),
+            ("AddressLookupTab1e1111111111111111111111111", "Address Lookup Table Program"),
+            ("BPFLoader1111111111111111111111111111111111", "BPF Loader (deprecated)"),
+            ("BPFLoader2111111111111111111111111111111111", "BPF Loader"),
+            ("BPFLoaderUpgradeab1e11111111111111111111111", "BPF Upgradeable Loader"),
+            ("LoaderV411111111111111111111111111111111111", "Loader v4"),
+            ("NativeLoader1111111111111111111111111111111", "Native Loader"),
+            ("Ed25519SigVerify111111111111111111111111111", "Ed25519 Signature Verification Program"),
+            ("KeccakSecp256k11111111111111111111111111111", "Secp256k1 Signature Verification Program"),
+            ("SysvarC1ock11111111111111111111111111111111", "Clock Sysvar"),
+            ("SysvarEpochSchedu1e111111111111111111111111", "Epoch Schedule Sysvar"),
+            ("SysvarFees111111111111111111111111111111111", "Fees Sysvar"),
+            ("Sysvar1nstructions1111111111111111111111111", "Instructions Sysvar"),
+            ("SysvarRecentB1ockHashes11111111111111111111", "Recent Blockhashes Sysvar"),
+            ("SysvarRent111111111111111111111111111111111", "Rent Sysvar"),
+            ("SysvarRewards111111111111111111111111111111", "Rewards Sysvar"),
+            ("SysvarS1otHashes111111111111111111111111111", "Slot Hashes Sysvar"),
+            ("SysvarS1otHistory11111111111111111111111111", "Slot History Sysvar"),
+            ("SysvarStakeHistory1111111111111111111111111", "Stake History Sysvar"),
+            ("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA", "SPL Token Program"),
+            ("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb", "SPL Token 2022 Program"),
+            ("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL", "SPL Associated Token Account Program"),
+            ("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr", "SPL Memo Program"),
+            ("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo", "SPL Memo Program (v1)"),
+            ("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy", "SPL Stake Pool Program"),
+            ("SwaPpA9LAaLfeLi3a68M4DjnLqgtticKg6CnyNwgAC8", "SPL Token Swap Program"),
+            ("namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX", "SPL Name Service Program"),
+            ("GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw", "SPL Governance Program"),
+            ("9xQeWvG816bUx9EPjHmaT23yvVM2ZWbrrpZb9PusVFin", "Serum DEX v3"),
+            ("srmqPvymJeFKQ4zGQed1GFppgkRHL9kaELCbyksJtPX", "OpenBook DEX"),
+            ("opnb2LAfJYbRMAHHvqjCwQxanZn7ReEHp1k81EohpZb", "OpenBook v2"),
+            ("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8", "Raydium AMM v4"),
+            ("CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK", "Raydium Concentrated Liquidity"),
+            ("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C", "Raydium CPMM"),
+            ("9W959DqEETiGZocYWCQPaJ6sBmUzgfxXfqGeTEdp3aQP", "Orca Token Swap v2"),
+            ("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc", "Orca Whirlpools"),
+            ("JUP4Fb2cqiRUcaTHdrPC8h2gNsA2ETXiPDD33WcGuJB", "Jupiter Aggregator v4"),
+            ("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4", "Jupiter Aggregator v6"),
+            ("MarBmsSgKXdrN1egZf5sqe1TMai9K1rChYNDJgjq7aD", "Marinade Finance"),
+            ("CrX7kMhLC3cSsXJdT7JDgqrRVWGnUpX3gfEfxxU2NVLi", "Lido for Solana"),
+            ("dRiftyHA39MWEi3m9aunc5MzRF1JYuBsbn6VPcn33UH", "Drift Protocol v2"),
+            ("mv3ekLzLbnVPNxjSKvqBpU3ZeZXPQdEC3bp5MDEBG68", "Mango Markets v3"),
+            ("4MangoMjqJ2firMokCjjGgoK8d4MXcrgL7XJaL3w6fVg", "Mango Markets v4"),
+            ("PhoeNiXZ8ByJGLkxNfZRnkUfjvmuYqLR89jjFHGqdXY", "Phoenix"),
+            ("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s", "Metaplex Token Metadata"),
+            ("cndy3Z4yapfJBmL3ShUp5exZKqR3z33thTzeNMm2gRZ", "Metaplex Candy Machine v2"),
+            ("CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR", "Metaplex Candy Machine v3"),
+            ("Guard1JwRhJkVH6XZhzoYxeBVQe872VH6QggF4BWmS9g", "Metaplex Candy Guard"),
+            ("hausS13jsjafwWwGqZTUQRmWyvyxn9EQpqMwV1PBBmk", "Metaplex Auction House"),
+            ("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY", "Metaplex Bubblegum"),
+            ("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d", "Metaplex Core"),
+        ])
+    })
+}
+
+#[derive(Deserialize, ToSchema)]
+struct WellKnownAddressRequest {
+    address: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct WellKnownAddressResponse {
+    address: String,
+    known: bool,
+    name: Option<&'static str>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/address/well-known",
+    tag = "address",
+    request_body = WellKnownAddressRequest,
+    responses(
+        (status = 200, body = SuccessResponse<WellKnownAddressResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn well_known_address(
+    Json(payload): Json<WellKnownAddressRequest>,
+) -> Result<Json<SuccessResponse<WellKnownAddressResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let address = parse_pubkey(&payload.address, "address")?.to_string();
+    let name = well_known_addresses().get(address.as_str()).copied();
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: WellKnownAddressResponse {
+            address,
+            known: name.is_some(),
+            name,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct LeaderScheduleRequest {
+    epoch: Option<u64>,
+    identity: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct LeaderScheduleResponse {
+    epoch: u64,
+    schedule: HashMap<String, Vec<usize>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    my_slots_count: Option<u64>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/get-slot-leaders-schedule",
+    tag = "rpc",
+    request_body = LeaderScheduleRequest,
+    responses(
+        (status = 200, body = SuccessResponse<LeaderScheduleResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "No leader schedule for the epoch", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_slot_leaders_schedule(
+    State(state): State<AppState>,
+    Json(payload): Json<LeaderScheduleRequest>,
+) -> Result<Json<SuccessResponse<LeaderScheduleResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let identity = payload
+        .identity
+        .as_deref()
+        .map(|identity| parse_pubkey(identity, "identity"))
+        .transpose()?;
+
+    let (epoch, schedule) = rpc_call(&state, move |rpc| {
+        let epoch = match payload.epoch {
+            Some(epoch) => epoch,
+            None => rpc.get_epoch_info()?.epoch,
+        };
+        let first_slot = rpc.get_epoch_schedule()?.get_first_slot_in_epoch(epoch);
+        Ok((epoch, rpc.get_leader_schedule(Some(first_slot))?))
+    })
+    .await?;
+
+    let schedule = schedule.ok_or_else(|| {
+        error_response(
+            StatusCode::NOT_FOUND,
+            format!("No leader schedule available for epoch {}", epoch),
+        )
+    })?;
+
+    let my_slots_count = identity.map(|identity| {
+        schedule
+            .get(&identity.to_string())
+            .map_or(0, |slots| slots.len() as u64)
+    });
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: LeaderScheduleResponse {
+            epoch,
+            schedule,
+            my_slots_count,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct GetBlockRequest {
+    slot: u64,
+    commitment: Option<String>,
+    max_supported_transaction_version: Option<u8>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct GetBlockResponse {
+    block_height: Option<u64>,
+    block_time: Option<i64>,
+    blockhash: String,
+    parent_slot: u64,
+    transaction_count: usize,
+    #[schema(value_type = Vec<Object>)]
+    transactions: Vec<EncodedTransactionWithStatusMeta>,
+    truncated: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/get-block",
+    tag = "rpc",
+    request_body = GetBlockRequest,
+    responses(
+        (status = 200, body = SuccessResponse<GetBlockResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_block(
+    State(state): State<AppState>,
+    Json(payload): Json<GetBlockRequest>,
+) -> Result<Json<SuccessResponse<GetBlockResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let commitment = parse_confirmed_commitment(payload.commitment.as_deref())?;
+
+    let config = RpcBlockConfig {
+        encoding: Some(UiTransactionEncoding::Base64),
+        transaction_details: Some(TransactionDetails::Full),
+        rewards: Some(false),
+        commitment,
+        max_supported_transaction_version: Some(payload.max_supported_transaction_version.unwrap_or(0)),
+    };
+    let block = rpc_call(&state, move |rpc| {
+        rpc.get_block_with_config(payload.slot, config)
+    })
+    .await?;
+
+    // Mainnet blocks can carry thousands of transactions; cap what we hold in memory
+    let mut transactions = block.transactions.unwrap_or_default();
+    let transaction_count = transactions.len();
+    let truncated = transaction_count > state.config.max_block_transactions;
+    transactions.truncate(state.config.max_block_transactions);
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: GetBlockResponse {
+            block_height: block.block_height,
+            block_time: block.block_time,
+            blockhash: block.blockhash,
+            parent_slot: block.parent_slot,
+            transaction_count,
+            transactions,
+            truncated,
+        },
+    }))
+}
+
+#[derive(Serialize, ToSchema)]
+struct InflationGovernorResponse {
+    initial: f64,
+    terminal: f64,
+    taper: f64,
+    foundation: f64,
+    foundation_term: f64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/get-inflation-governor",
+    tag = "rpc",
+    responses(
+        (status = 200, body = SuccessResponse<InflationGovernorResponse>),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_inflation_governor(
+    State(state): State<AppState>,
+) -> Result<Json<SuccessResponse<InflationGovernorResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let governor = rpc_call(&state, |rpc| rpc.get_inflation_governor()).await?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: InflationGovernorResponse {
+            initial: governor.initial,
+            terminal: governor.terminal,
+            taper: governor.taper,
+            foundation: governor.foundation,
+            foundation_term: governor.foundation_term,
+        },
+    }))
+}
+
+#[derive(Serialize, ToSchema)]
+struct InflationRateResponse {
+    total: f64,
+    validator: f64,
+    foundation: f64,
+    epoch: u64,
+}
+
+#[utoipa::path(
+    get,
+    path = "/rpc/inflation-rate",
+    tag = "rpc",
+    responses(
+        (status = 200, body = SuccessResponse<InflationRateResponse>),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_inflation_rate(
+    State(state): State<AppState>,
+) -> Result<Json<SuccessResponse<InflationRateResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let rate = rpc_call(&state, |rpc| rpc.get_inflation_rate()).await?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: InflationRateResponse {
+            total: rate.total,
+            validator: rate.validator,
+            foundation: rate.foundation,
+            epoch: rate.epoch,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct AccountsBySizeRequest {
+    program_id: String,
+    data_size: usize,
+    limit: u16,
+}
+
+#[derive(Serialize, ToSchema)]
+struct ProgramAccountEntry {
+    pubkey: String,
+    lamports: u64,
+    owner: String,
+    executable: bool,
+    data: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct AccountsBySizeResponse {
+    accounts: Vec<ProgramAccountEntry>,
+    total_found: usize,
+    truncated: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/get-accounts-by-program-id-with-size",
+    tag = "rpc",
+    request_body = AccountsBySizeRequest,
+    responses(
+        (status = 200, body = SuccessResponse<AccountsBySizeResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_accounts_by_program_id_with_size(
+    State(state): State<AppState>,
+    Json(payload): Json<AccountsBySizeRequest>,
+) -> Result<Json<SuccessResponse<AccountsBySizeResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let program_id = parse_pubkey(&payload.program_id, "program_id")?;
+
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::DataSize(payload.data_size as u64)]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    let mut accounts = rpc_call(&state, move |rpc| {
+        rpc.get_program_accounts_with_config(&program_id, config.clone())
+    })
+    .await?;
+
+    // getProgramAccounts has no native limit, so the cap is applied here
+    let total_found = accounts.len();
+    accounts.truncate(payload.limit as usize);
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: AccountsBySizeResponse {
+            accounts: accounts
+                .into_iter()
+                .map(|(pubkey, account)| ProgramAccountEntry {
+                    pubkey: pubkey.to_string(),
+                    lamports: account.lamports,
+                    owner: account.owner.to_string(),
+                    executable: account.executable,
+                    data: general_purpose::STANDARD.encode(&account.data),
+                })
+                .collect(),
+            total_found,
+            truncated: total_found > payload.limit as usize,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct MemoTransferRequest {
+    account: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct MemoTransferResponse {
+    account: String,
+    memo_required: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<&'static str>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/get-memo-transfer-required",
+    tag = "token",
+    request_body = MemoTransferRequest,
+    responses(
+        (status = 200, body = SuccessResponse<MemoTransferResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+        (status = 422, description = "Account is not a Token 2022 token account", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_memo_transfer_required(
+    State(state): State<AppState>,
+    Json(payload): Json<MemoTransferRequest>,
+) -> Result<Json<SuccessResponse<MemoTransferResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let pubkey = parse_pubkey(&payload.account, "account")?;
+    let account = fetch_account(&state, pubkey).await?;
+
+    let (memo_required, note) = if account.owner == spl_token::ID {
+        (false, Some("Classic SPL Token account, no memo extension"))
+    } else if account.owner == spl_token_2022::ID {
+        let token_account =
+            StateWithExtensionsOwned::<spl_token_2022::state::Account>::unpack(account.data)
+                .map_err(|_| {
+                    error_response(
+                        StatusCode::UNPROCESSABLE_ENTITY,
+                        "Account is not a valid Token 2022 token account",
+                    )
+                })?;
+        let memo_required = token_account
+            .get_extension::<MemoTransfer>()
+            .map(|extension| bool::from(extension.require_incoming_transfer_memos))
+            .unwrap_or(false);
+        (memo_required, None)
+    } else {
+        return Err(error_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Account is not owned by a token program",
+        ));
+    };
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: MemoTransferResponse {
+            account: payload.account,
+            memo_required,
+            note,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct CheckTransferAuthorityRequest {
+    token_account: String,
+    proposed_authority: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct CheckTransferAuthorityResponse {
+    authorized: bool,
+    authority_type: &'static str,
+    owner: String,
+    delegate: Option<String>,
+    delegated_amount: u64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/check-transfer-authority",
+    tag = "token",
+    request_body = CheckTransferAuthorityRequest,
+    responses(
+        (status = 200, body = SuccessResponse<CheckTransferAuthorityResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+        (status = 422, description = "Account is not a valid token account", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn check_transfer_authority(
+    State(state): State<AppState>,
+    Json(payload): Json<CheckTransferAuthorityRequest>,
+) -> Result<Json<SuccessResponse<CheckTransferAuthorityResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let token_account = parse_pubkey(&payload.token_account, "token_account")?;
+    let proposed_authority = parse_pubkey(&payload.proposed_authority, "proposed_authority")?;
+
+    let account = unpack_token_account(fetch_account(&state, token_account).await?)?;
+    let delegate: Option<Pubkey> = account.delegate.into();
+
+    // A delegate with nothing left to spend cannot authorize a transfer
+    let authority_type = if account.owner == proposed_authority {
+        "owner"
+    } else if delegate == Some(proposed_authority) && account.delegated_amount > 0 {
+        "delegate"
+    } else {
+        "none"
+    };
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: CheckTransferAuthorityResponse {
+            authorized: authority_type != "none",
+            authority_type,
+            owner: account.owner.to_string(),
+            delegate: delegate.map(|delegate| delegate.to_string()),
+            delegated_amount: account.delegated_amount,
+        },
+    }))
+}
+
+const MAX_BLOCKS_LIMIT: u64 = 500_000;
+
+#[derive(Deserialize, ToSchema)]
+struct BlocksWithLimitRequest {
+    start_slot: u64,
+    limit: u64,
+    commitment: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct BlocksWithLimitResponse {
+    slots: Vec<u64>,
+    count: usize,
+    next_start_slot: Option<u64>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/get-confirmed-blocks-with-limit",
+    tag = "rpc",
+    request_body = BlocksWithLimitRequest,
+    responses(
+        (status = 200, body = SuccessResponse<BlocksWithLimitResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_confirmed_blocks_with_limit(
+    State(state): State<AppState>,
+    Json(payload): Json<BlocksWithLimitRequest>,
+) -> Result<Json<SuccessResponse<BlocksWithLimitResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let commitment = parse_confirmed_commitment(payload.commitment.as_deref())?;
+    let limit = payload.limit.min(MAX_BLOCKS_LIMIT) as usize;
+
+    let slots = rpc_call(&state, move |rpc| {
+        rpc.get_blocks_with_limit_and_commitment(
+            payload.start_slot,
+            limit,
+            commitment.unwrap_or(rpc.commitment()),
+        )
+    })
+    .await?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: BlocksWithLimitResponse {
+            count: slots.len(),
+            next_start_slot: slots.last().map(|slot| slot + 1),
+            slots,
+        },
+    }))
+}
+
+const MAX_SIGNATURE_BATCH_SIZE: u16 = 1000;
+const MAX_SIGNATURE_RPC_CALLS: u8 = 20;
+
+#[derive(Deserialize, ToSchema)]
+struct SignaturesUntilRequest {
+    account: String,
+    until_signature: String,
+    commitment: Option<String>,
+    batch_size: u16,
+}
+
+#[derive(Serialize, ToSchema)]
+struct SignatureEntry {
+    signature: String,
+    slot: u64,
+    block_time: Option<i64>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct SignaturesUntilResponse {
+    signatures: Vec<SignatureEntry>,
+    found_until: bool,
+    total_fetched: usize,
+    rpc_calls: u8,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/get-signatures-for-address-until-not-found",
+    tag = "rpc",
+    request_body = SignaturesUntilRequest,
+    responses(
+        (status = 200, body = SuccessResponse<SignaturesUntilResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_signatures_for_address_until_not_found(
+    State(state): State<AppState>,
+    Json(payload): Json<SignaturesUntilRequest>,
+) -> Result<Json<SuccessResponse<SignaturesUntilResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let account = parse_pubkey(&payload.account, "account")?;
+    let until_signature = TransactionSignature::from_str(&payload.until_signature)
+        .map_err(|_| error_response(StatusCode::BAD_REQUEST, "Invalid until_signature"))?
+        .to_string();
+    let commitment = parse_confirmed_commitment(payload.commitment.as_deref())?;
+    let batch_size = payload.batch_size.clamp(1, MAX_SIGNATURE_BATCH_SIZE) as usize;
+
+    let response = rpc_call(&state, move |rpc| {
+        let mut signatures = Vec::new();
+        let mut found_until = false;
+        let mut total_fetched = 0;
+        let mut rpc_calls = 0;
+        let mut before = None;
+
+        // Walk backwards page by page until the checkpoint shows up or history runs out
+        while rpc_calls < MAX_SIGNATURE_RPC_CALLS {
+            let page = rpc.get_signatures_for_address_with_config(
+                &account,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until: None,
+                    limit: Some(batch_size),
+                    commitment,
+                },
+            )?;
+            rpc_calls += 1;
+            total_fetched += page.len();
+
+            let page_len = page.len();
+            for status in page {
+                if status.signature == until_signature {
+                    found_until = true;
+                    break;
+                }
+                before = TransactionSignature::from_str(&status.signature).ok();
+                signatures.push(SignatureEntry {
+                    signature: status.signature,
+                    slot: status.slot,
+                    block_time: status.block_time,
+                });
+            }
+
+            if found_until || page_len < batch_size {
+                break;
+            }
+        }
+
+        Ok(SignaturesUntilResponse {
+            signatures,
+            found_until,
+            total_fetched,
+            rpc_calls,
+        })
+    })
+    .await?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: response,
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct DeriveStakeAccountRequest {
+    vote_account: String,
+    seed: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct DeriveStakeAccountResponse {
+    vote_account: String,
+    seed: String,
+    address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    common_seeds: Option<BTreeMap<String, String>>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/address/derive-stake-account",
+    tag = "address",
+    request_body = DeriveStakeAccountRequest,
+    responses(
+        (status = 200, body = SuccessResponse<DeriveStakeAccountResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn derive_stake_account(
+    Json(payload): Json<DeriveStakeAccountRequest>,
+) -> Result<Json<SuccessResponse<DeriveStakeAccountResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let vote_account = parse_pubkey(&payload.vote_account, "vote_account")?;
+
+    let address = Pubkey::create_with_seed(&vote_account, &payload.seed, &stake::program::ID)
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, format!("Invalid seed: {}", e)))?;
+
+    // The first stake account uses seed "0", so hand back the next few alongside it
+    let common_seeds = (payload.seed == "0").then(|| {
+        (0..10)
+            .filter_map(|index| {
+                let seed = index.to_string();
+                Pubkey::create_with_seed(&vote_account, &seed, &stake::program::ID)
+                    .ok()
+                    .map(|address| (seed, address.to_string()))
+            })
+            .collect()
+    });
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: DeriveStakeAccountResponse {
+            vote_account: payload.vote_account,
+            seed: payload.seed,
+            address: address.to_string(),
+            common_seeds,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct MintCloseAuthorityRequest {
+    mint: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct MintCloseAuthorityResponse {
+    mint: String,
+    has_close_authority: bool,
+    close_authority: Option<String>,
+    risk_level: &'static str,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/get-mint-close-authority",
+    tag = "token",
+    request_body = MintCloseAuthorityRequest,
+    responses(
+        (status = 200, body = SuccessResponse<MintCloseAuthorityResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+        (status = 422, description = "Account is not a valid SPL mint", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_mint_close_authority(
+    State(state): State<AppState>,
+    Json(payload): Json<MintCloseAuthorityRequest>,
+) -> Result<Json<SuccessResponse<MintCloseAuthorityResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let pubkey = parse_pubkey(&payload.mint, "mint")?;
+    let mint = unpack_mint(fetch_account(&state, pubkey).await?)?;
+
+    let close_authority: Option<Pubkey> = mint
+        .get_extension::<MintCloseAuthority>()
+        .ok()
+        .and_then(|extension| Option::<Pubkey>::from(extension.close_authority));
+    let mint_authority: Option<Pubkey> = mint.base.mint_authority.into();
+
+    // A close authority held by someone other than the mint authority is the riskiest case;
+    // one shared with the mint authority matters mostly while there is supply outstanding.
+    let risk_level = match close_authority {
+        None => "none",
+        Some(authority) if Some(authority) != mint_authority => "high",
+        Some(_) if mint.base.supply > 0 => "medium",
+        Some(_) => "low",
+    };
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: MintCloseAuthorityResponse {
+            mint: payload.mint,
+            has_close_authority: close_authority.is_some(),
+            close_authority: close_authority.map(|authority| authority.to_string()),
+            risk_level,
+        },
+    }))
+}
+
+#[derive(Serialize, ToSchema)]
+struct TotalSupplyResponse {
+    circulating_lamports: u64,
+    circulating_sol: f64,
+    non_circulating_lamports: u64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/get-total-supply",
+    tag = "rpc",
+    responses(
+        (status = 200, body = SuccessResponse<TotalSupplyResponse>),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_total_supply(
+    State(state): State<AppState>,
+) -> Result<Json<SuccessResponse<TotalSupplyResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let supply = rpc_call(&state, |rpc| rpc.supply()).await?.value;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: TotalSupplyResponse {
+            circulating_lamports: supply.circulating,
+            circulating_sol: lamports_to_sol(supply.circulating),
+            non_circulating_lamports: supply.non_circulating,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct FreezeAuthorityRequest {
+    mint: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct FreezeAuthorityResponse {
+    mint: String,
+    has_freeze_authority: bool,
+    freeze_authority: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_accounts_start_frozen: Option<bool>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/get-freeze-authority",
+    tag = "token",
+    request_body = FreezeAuthorityRequest,
+    responses(
+        (status = 200, body = SuccessResponse<FreezeAuthorityResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+        (status = 422, description = "Account is not a valid SPL mint", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_freeze_authority(
+    State(state): State<AppState>,
+    Json(payload): Json<FreezeAuthorityRequest>,
+) -> Result<Json<SuccessResponse<FreezeAuthorityResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let pubkey = parse_pubkey(&payload.mint, "mint")?;
+    let mint = unpack_mint(fetch_account(&state, pubkey).await?)?;
+
+    let freeze_authority: Option<Pubkey> = mint.base.freeze_authority.into();
+    let new_accounts_start_frozen = mint
+        .get_extension::<DefaultAccountState>()
+        .ok()
+        .map(|extension| extension.state == AccountState::Frozen as u8);
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: FreezeAuthorityResponse {
+            mint: payload.mint,
+            has_freeze_authority: freeze_authority.is_some(),
+            freeze_authority: freeze_authority.map(|authority| authority.to_string()),
+            new_accounts_start_frozen,
+        },
+    }))
+}
+
+const MAX_HISTORY_SEARCH_LIMIT: u16 = 100;
+
+#[derive(Deserialize, ToSchema)]
+struct SearchTransactionHistoryRequest {
+    program_id: String,
+    account: String,
+    commitment: Option<String>,
+    limit: u16,
+}
+
+#[derive(Serialize, ToSchema)]
+struct SearchTransactionHistoryResponse {
+    matches: Vec<SignatureEntry>,
+    scanned: usize,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/search-transaction-history",
+    tag = "rpc",
+    request_body = SearchTransactionHistoryRequest,
+    responses(
+        (status = 200, body = SuccessResponse<SearchTransactionHistoryResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn search_transaction_history(
+    State(state): State<AppState>,
+    Json(payload): Json<SearchTransactionHistoryRequest>,
+) -> Result<Json<SuccessResponse<SearchTransactionHistoryResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let program_id = parse_pubkey(&payload.program_id, "program_id")?;
+    let account = parse_pubkey(&payload.account, "account")?;
+    let commitment = parse_confirmed_commitment(payload.commitment.as_deref())?
+        .unwrap_or(CommitmentConfig::finalized());
+    // Every candidate costs an extra getTransaction call, so keep the window small
+    let limit = payload.limit.clamp(1, MAX_HISTORY_SEARCH_LIMIT) as usize;
+
+    let response = rpc_call(&state, move |rpc| {
+        let statuses = rpc.get_signatures_for_address_with_config(
+            &account,
+            GetConfirmedSignaturesForAddress2Config {
+                before: None,
+                until: None,
+                limit: Some(limit),
+                commitment: Some(commitment),
+            },
+        )?;
+        let scanned = statuses.len();
+
+        let mut matches = Vec::new();
+        for status in statuses {
+            let Ok(signature) = TransactionSignature::from_str(&status.signature) else {
+                continue;
+            };
+            let transaction = rpc.get_transaction_with_config(
+                &signature,
+                RpcTransactionConfig {
+                    encoding: Some(UiTransactionEncoding::Base64),
+                    commitment: Some(commitment),
+                    max_supported_transaction_version: Some(0),
+                },
+            )?;
+
+            // Programs are always static keys, so lookup tables never hide an invoked program
+            let invokes_program = transaction
+                .transaction
+                .transaction
+                .decode()
+                .is_some_and(|tx| {
+                    let keys = tx.message.static_account_keys();
+                    tx.message.instructions().iter().any(|ix| {
+                        keys.get(ix.program_id_index as usize) == Some(&program_id)
+                    })
+                });
+
+            if invokes_program {
+                matches.push(SignatureEntry {
+                    signature: status.signature,
+                    slot: status.slot,
+                    block_time: status.block_time,
+                });
+            }
+        }
+
+        Ok(SearchTransactionHistoryResponse { matches, scanned })
+    })
+    .await?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: response,
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct CreateAndDelegateStakeRequest {
+    from: String,
+    stake_account: String,
+    vote_account: String,
+    lamports: u64,
+    authorized_staker: String,
+    authorized_withdrawer: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct CreateAndDelegateStakeResponse {
+    instructions: Vec<InstructionData>,
+    stake_lamports: u64,
+    rent_exempt_reserve: u64,
+    total_lamports: u64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/create-and-delegate-stake",
+    tag = "token",
+    request_body = CreateAndDelegateStakeRequest,
+    responses(
+        (status = 200, body = SuccessResponse<CreateAndDelegateStakeResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn create_and_delegate_stake(
+    State(state): State<AppState>,
+    Json(payload): Json<CreateAndDelegateStakeRequest>,
+) -> Result<Json<SuccessResponse<CreateAndDelegateStakeResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let from = parse_pubkey(&payload.from, "from")?;
+    let stake_account = parse_pubkey(&payload.stake_account, "stake_account")?;
+    let vote_account = parse_pubkey(&payload.vote_account, "vote_account")?;
+    let authorized_staker = parse_pubkey(&payload.authorized_staker, "authorized_staker")?;
+    let authorized_withdrawer = parse_pubkey(&payload.authorized_withdrawer, "authorized_withdrawer")?;
+
+    if payload.lamports == 0 {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "lamports must be greater than 0",
+        ));
+    }
+
+    let rent_exempt_reserve = rpc_call(&state, |rpc| {
+        rpc.get_minimum_balance_for_rent_exemption(StakeStateV2::size_of())
+    })
+    .await?;
+    let total_lamports = payload
+        .lamports
+        .checked_add(rent_exempt_reserve)
+        .ok_or_else(|| error_response(StatusCode::BAD_REQUEST, "Total lamports overflows u64"))?;
+
+    // create_account yields CreateAccount + Initialize; delegation is appended after them
+    let mut instructions = stake_instruction::create_account(
+        &from,
+        &stake_account,
+        &Authorized {
+            staker: authorized_staker,
+            withdrawer: authorized_withdrawer,
+        },
+        &Lockup::default(),
+        total_lamports,
+    );
+    instructions.push(stake_instruction::delegate_stake(
+        &stake_account,
+        &authorized_staker,
+        &vote_account,
+    ));
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: CreateAndDelegateStakeResponse {
+            instructions: instructions.into_iter().map(instruction_data).collect(),
+            stake_lamports: payload.lamports,
+            rent_exempt_reserve,
+            total_lamports,
+        },
+    }))
+}
+
+#[derive(Serialize, ToSchema)]
+struct RecentBlockhashFeesResponse {
+    blockhash: String,
+    last_valid_block_height: u64,
+    lamports_per_signature: u64,
+    min_lamports_per_signature: u64,
+    target_lamports_per_signature: u64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/get-recent-blockhash-fees",
+    tag = "rpc",
+    responses(
+        (status = 200, body = SuccessResponse<RecentBlockhashFeesResponse>),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_recent_blockhash_fees(
+    State(state): State<AppState>,
+) -> Result<Json<SuccessResponse<RecentBlockhashFeesResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let (governor, blockhash) = tokio::join!(
+        // getFeeRateGovernor is deprecated upstream but is still the only source of the fee bounds
+        #[allow(deprecated)]
+        rpc_call(&state, |rpc| rpc.get_fee_rate_governor()),
+        rpc_call(&state, |rpc| {
+            rpc.get_latest_blockhash_with_commitment(rpc.commitment())
+        }),
+    );
+    let governor = governor?.value;
+    let (blockhash, last_valid_block_height) = blockhash?;
+
+    // The governor's lamports_per_signature is not sent over the wire, so price a
+    // single-signer message against the fresh blockhash instead.
+    let message = Message::new_with_blockhash(&[], Some(&Pubkey::new_unique()), &blockhash);
+    let lamports_per_signature = rpc_call(&state, move |rpc| rpc.get_fee_for_message(&message)).await?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: RecentBlockhashFeesResponse {
+            blockhash: blockhash.to_string(),
+            last_valid_block_height,
+            lamports_per_signature,
+            min_lamports_per_signature: governor.min_lamports_per_signature,
+            target_lamports_per_signature: governor.target_lamports_per_signature,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct CloseMintRequest {
+    mint: String,
+    destination: String,
+    close_authority: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/close-authority/close-mint",
+    tag = "token",
+    request_body = CloseMintRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "close_authority does not match the mint's close authority", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+        (status = 422, description = "Account is not a Token 2022 mint with a close authority and zero supply", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn close_mint(
+    State(state): State<AppState>,
+    Json(payload): Json<CloseMintRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let mint_pubkey = parse_pubkey(&payload.mint, "mint")?;
+    let destination = parse_pubkey(&payload.destination, "destination")?;
+    let close_authority = parse_pubkey(&payload.close_authority, "close_authority")?;
+
+    let account = fetch_account(&state, mint_pubkey).await?;
+    if account.owner != spl_token_2022::ID {
+        return Err(error_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Only Token 2022 mints can be closed",
+        ));
+    }
+    let mint = unpack_mint(account)?;
+
+    let on_chain_authority = mint
+        .get_extension::<MintCloseAuthority>()
+        .ok()
+        .and_then(|extension| Option::<Pubkey>::from(extension.close_authority))
+        .ok_or_else(|| {
+            error_response(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "Mint does not have a close authority",
+            )
+        })?;
+    if on_chain_authority != close_authority {
+        return Err(error_response(
+            StatusCode::FORBIDDEN,
+            "Provided close_authority does not match on-chain close authority",
+        ));
+    }
+    if mint.base.supply > 0 {
+        return Err(error_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Mint supply must be zero before it can be closed",
+        ));
+    }
+
+    let ix = spl_token_2022::instruction::close_account(
+        &spl_token_2022::ID,
+        &mint_pubkey,
+        &destination,
+        &close_authority,
+        &[],
+    )
+    .map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to create instruction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(ix),
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct ProgramAccountsForMintRequest {
+    mint: String,
+    program: Option<String>,
+    limit: u32,
+    #[serde(default)]
+    min_balance: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+struct MintTokenAccountEntry {
+    pubkey: String,
+    owner: String,
+    amount: u64,
+    state: &'static str,
+}
+
+// The mint is the first field of a token account, so a memcmp at offset 0 selects its holders.
+// Classic accounts are fixed-size; Token 2022 accounts grow with extensions and cannot be size-filtered.
+fn token_accounts_for_mint_filters(program_id: &Pubkey, mint: &Pubkey) -> Vec<RpcFilterType> {
+    let mut filters = vec![RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+        0,
+        &mint.to_bytes(),
+    ))];
+    if *program_id == spl_token::ID {
+        filters.push(RpcFilterType::DataSize(spl_token::state::Account::LEN as u64));
+    }
+    filters
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/get-program-accounts-for-mint",
+    tag = "token",
+    request_body = ProgramAccountsForMintRequest,
+    responses(
+        (status = 200, body = SuccessResponse<Vec<MintTokenAccountEntry>>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_program_accounts_for_mint(
+    State(state): State<AppState>,
+    Json(payload): Json<ProgramAccountsForMintRequest>,
+) -> Result<Json<SuccessResponse<Vec<MintTokenAccountEntry>>>, (StatusCode, Json<ErrorResponse>)> {
+    let mint = parse_pubkey(&payload.mint, "mint")?;
+    let program_id = match payload.program.as_deref() {
+        None | Some("spl_token") => spl_token::ID,
+        Some("spl_token_2022") => spl_token_2022::ID,
+        Some(other) => {
+            return Err(error_response(
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "Invalid program '{}'; expected spl_token or spl_token_2022",
+                    other
+                ),
+            ))
+        }
+    };
+
+    let config = RpcProgramAccountsConfig {
+        filters: Some(token_accounts_for_mint_filters(&program_id, &mint)),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    let accounts = rpc_call(&state, move |rpc| {
+        rpc.get_program_accounts_with_config(&program_id, config.clone())
+    })
+    .await?;
+
+    let entries = accounts
+        .into_iter()
+        .filter_map(|(pubkey, account)| {
+            let token_account = unpack_token_account(account).ok()?;
+            (token_account.amount >= payload.min_balance).then(|| MintTokenAccountEntry {
+                pubkey: pubkey.to_string(),
+                owner: token_account.owner.to_string(),
+                amount: token_account.amount,
+                state: if token_account.is_frozen() {
+                    "frozen"
+                } else {
+                    "initialized"
+                },
+            })
+        })
+        .take(payload.limit as usize)
+        .collect();
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: entries,
+    }))
+}
+
+const SUPERMAJORITY_STAKE_PERCENTAGE: f64 = 200.0 / 3.0;
+
+#[derive(Deserialize, ToSchema)]
+struct BlockCommitmentRequest {
+    slot: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+struct BlockCommitmentResponse {
+    slot: u64,
+    commitment: Option<Vec<u64>>,
+    total_stake: u64,
+    confirmed_stake_percentage: f64,
+    status: &'static str,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/get-block-commitment",
+    tag = "rpc",
+    request_body = BlockCommitmentRequest,
+    responses(
+        (status = 200, body = SuccessResponse<BlockCommitmentResponse>),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_block_commitment(
+    State(state): State<AppState>,
+    Json(payload): Json<BlockCommitmentRequest>,
+) -> Result<Json<SuccessResponse<BlockCommitmentResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    // The blocking client has no wrapper for getBlockCommitment, so issue the raw request
+    let block_commitment: RpcBlockCommitment<Vec<u64>> = rpc_call(&state, move |rpc| {
+        rpc.send(
+            RpcRequest::Custom {
+                method: "getBlockCommitment",
+            },
+            json!([payload.slot]),
+        )
+    })
+    .await?;
+
+    // Each bucket holds stake voting at a given lockout depth; their sum is all stake voting on the slot
+    let voted_stake: u64 = block_commitment
+        .commitment
+        .as_ref()
+        .map_or(0, |commitment| commitment.iter().sum());
+    let confirmed_stake_percentage = if block_commitment.total_stake > 0 {
+        voted_stake as f64 / block_commitment.total_stake as f64 * 100.0
+    } else {
+        0.0
+    };
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: BlockCommitmentResponse {
+            slot: payload.slot,
+            commitment: block_commitment.commitment,
+            total_stake: block_commitment.total_stake,
+            confirmed_stake_percentage,
+            status: if confirmed_stake_percentage >= SUPERMAJORITY_STAKE_PERCENTAGE {
+                "confirmed"
+            } else {
+                "not_confirmed"
+            },
+        },
+    }))
+}
+
+// getTokenLargestAccounts never returns more than 20 entries
+const MAX_TOP_HOLDERS: u8 = 20;
+
+#[derive(Deserialize, ToSchema)]
+struct SupplyAndHoldersRequest {
+    mint: String,
+    top_n: u8,
+    commitment: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct TopHolder {
+    address: String,
+    amount: u64,
+    percentage: f64,
+}
+
+#[derive(Serialize, ToSchema)]
+struct SupplyAndHoldersResponse {
+    decimals: u8,
+    total_supply: u64,
+    top_holders: Vec<TopHolder>,
+    top_n_concentration: f64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/get-supply-and-largest-holders",
+    tag = "token",
+    request_body = SupplyAndHoldersRequest,
+    responses(
+        (status = 200, body = SuccessResponse<SupplyAndHoldersResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_supply_and_largest_holders(
+    State(state): State<AppState>,
+    Json(payload): Json<SupplyAndHoldersRequest>,
+) -> Result<Json<SuccessResponse<SupplyAndHoldersResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let mint = parse_pubkey(&payload.mint, "mint")?;
+    let commitment = parse_commitment(payload.commitment.as_deref())?;
+
+    let (supply, largest) = tokio::join!(
+        rpc_call(&state, move |rpc| {
+            rpc.get_token_supply_with_commitment(&mint, commitment.unwrap_or(rpc.commitment()))
+        }),
+        rpc_call(&state, move |rpc| {
+            rpc.get_token_largest_accounts_with_commitment(
+                &mint,
+                commitment.unwrap_or(rpc.commitment()),
+            )
+        }),
+    );
+    let supply = supply?.value;
+    let largest = largest?.value;
+
+    let parse_amount = |amount: &str| {
+        amount.parse::<u64>().map_err(|_| {
+            error_response(StatusCode::BAD_GATEWAY, "RPC returned an invalid token amount")
+        })
+    };
+    let total_supply = parse_amount(&supply.amount)?;
+
+    let mut top_holders = Vec::new();
+    for holder in largest
+        .into_iter()
+        .take(payload.top_n.min(MAX_TOP_HOLDERS) as usize)
+    {
+        let amount = parse_amount(&holder.amount.amount)?;
+        let percentage = if total_supply > 0 {
+            amount as f64 / total_supply as f64 * 100.0
+        } else {
+            0.0
+        };
+        top_holders.push(TopHolder {
+            address: holder.address,
+            amount,
+            percentage,
+        });
+    }
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: SupplyAndHoldersResponse {
+            decimals: supply.decimals,
+            total_supply,
+            top_n_concentration: top_holders.iter().map(|holder| holder.percentage).sum(),
+            top_holders,
+        },
+    }))
+}
+
+const MAX_OWNERSHIP_CHECKS: usize = 50;
+
+#[derive(Deserialize, ToSchema)]
+struct OwnershipCheckEntry {
+    pubkey: String,
+    expected_owner: String,
+}
+
+#[derive(Deserialize, ToSchema)]
+struct VerifyOwnershipRequest {
+    accounts: Vec<OwnershipCheckEntry>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct OwnershipCheckResult {
+    pubkey: String,
+    expected_owner: String,
+    actual_owner: Option<String>,
+    matches: bool,
+    exists: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "/address/verify-program-account-ownership",
+    tag = "address",
+    request_body = VerifyOwnershipRequest,
+    responses(
+        (status = 200, body = SuccessResponse<Vec<OwnershipCheckResult>>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn verify_program_account_ownership(
+    State(state): State<AppState>,
+    Json(payload): Json<VerifyOwnershipRequest>,
+) -> Result<Json<SuccessResponse<Vec<OwnershipCheckResult>>>, (StatusCode, Json<ErrorResponse>)> {
+    if payload.accounts.len() > MAX_OWNERSHIP_CHECKS {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Too many accounts; maximum is {}", MAX_OWNERSHIP_CHECKS),
+        ));
+    }
+
+    let mut pubkeys = Vec::with_capacity(payload.accounts.len());
+    let mut expected_owners = Vec::with_capacity(payload.accounts.len());
+    for (index, entry) in payload.accounts.iter().enumerate() {
+        pubkeys.push(parse_pubkey(&entry.pubkey, &format!("accounts[{}].pubkey", index))?);
+        expected_owners.push(parse_pubkey(
+            &entry.expected_owner,
+            &format!("accounts[{}].expected_owner", index),
+        )?);
+    }
+
+    // One getMultipleAccounts round-trip covers the whole batch
+    let accounts = rpc_call(&state, move |rpc| rpc.get_multiple_accounts(&pubkeys)).await?;
+
+    let results = payload
+        .accounts
+        .into_iter()
+        .zip(expected_owners)
+        .zip(accounts)
+        .map(|((entry, expected_owner), account)| OwnershipCheckResult {
+            pubkey: entry.pubkey,
+            expected_owner: entry.expected_owner,
+            actual_owner: account.as_ref().map(|account| account.owner.to_string()),
+            matches: account
+                .as_ref()
+                .is_some_and(|account| account.owner == expected_owner),
+            exists: account.is_some(),
+        })
+        .collect();
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: results,
+    }))
+}
+
+#[derive(Deserialize, Default, ToSchema)]
+struct StakesInCurrentEpochRequest {
+    min_activated_stake: Option<u64>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct ValidatorStake {
+    vote_pubkey: String,
+    node_pubkey: String,
+    activated_stake: u64,
+    commission: u8,
+    epoch_vote_account: bool,
+    delinquent: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/get-stakes-in-current-epoch",
+    tag = "rpc",
+    request_body(content = StakesInCurrentEpochRequest, description = "Optional; defaults apply when omitted"),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<ValidatorStake>>),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_stakes_in_current_epoch(
+    State(state): State<AppState>,
+    payload: Option<Json<StakesInCurrentEpochRequest>>,
+) -> Result<Json<SuccessResponse<Vec<ValidatorStake>>>, (StatusCode, Json<ErrorResponse>)> {
+    let min_activated_stake = payload
+        .map(|Json(payload)| payload)
+        .unwrap_or_default()
+        .min_activated_stake
+        .unwrap_or(0);
+
+    let vote_accounts = rpc_call(&state, |rpc| rpc.get_vote_accounts()).await?;
+
+    let current = vote_accounts.current.into_iter().map(|info| (info, false));
+    let delinquent = vote_accounts.delinquent.into_iter().map(|info| (info, true));
+    let mut validators: Vec<ValidatorStake> = current
+        .chain(delinquent)
+        .filter(|(info, _)| info.activated_stake >= min_activated_stake)
+        .map(|(info, delinquent)| ValidatorStake {
+            vote_pubkey: info.vote_pubkey,
+            node_pubkey: info.node_pubkey,
+            activated_stake: info.activated_stake,
+            commission: info.commission,
+            epoch_vote_account: info.epoch_vote_account,
+            delinquent,
+        })
+        .collect();
+    validators.sort_by_key(|validator| std::cmp::Reverse(validator.activated_stake));
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: validators,
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct NonceAccountRequest {
+    nonce_account: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct NonceFeeCalculator {
+    lamports_per_signature: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+struct NonceAccountResponse {
+    state: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authority: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blockhash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fee_calculator: Option<NonceFeeCalculator>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/rpc/get-nonce-account",
+    tag = "rpc",
+    request_body = NonceAccountRequest,
+    responses(
+        (status = 200, body = SuccessResponse<NonceAccountResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+        (status = 422, description = "Account is not a nonce account", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_nonce_account(
+    State(state): State<AppState>,
+    Json(payload): Json<NonceAccountRequest>,
+) -> Result<Json<SuccessResponse<NonceAccountResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let pubkey = parse_pubkey(&payload.nonce_account, "nonce_account")?;
+    let account = fetch_account(&state, pubkey).await?;
+
+    let nonce_state = nonce_utils::state_from_account(&account).map_err(|e| {
+        error_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("Account is not a valid nonce account: {}", e),
+        )
+    })?;
+
+    let response = match nonce_state {
+        NonceState::Uninitialized => NonceAccountResponse {
+            state: "uninitialized",
+            authority: None,
+            blockhash: None,
+            fee_calculator: None,
+        },
+        NonceState::Initialized(data) => NonceAccountResponse {
+            state: "initialized",
+            authority: Some(data.authority.to_string()),
+            blockhash: Some(data.blockhash().to_string()),
+            fee_calculator: Some(NonceFeeCalculator {
+                lamports_per_signature: data.fee_calculator.lamports_per_signature,
+            }),
+        },
+    };
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: response,
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+enum SeedInput {
+    String(String),
+    Pubkey(String),
+    Base64(String),
+}
+
+fn decode_seed(index: usize, seed: &SeedInput) -> Result<Vec<u8>, (StatusCode, Json<ErrorResponse>)> {
+    let bytes = match seed {
+        SeedInput::String(value) => value.as_bytes().to_vec(),
+        SeedInput::Pubkey(value) => parse_pubkey(value, &format!("seeds[{}]", index))?
+            .to_bytes()
+            .to_vec(),
+        SeedInput::Base64(value) => general_purpose::STANDARD.decode(value).map_err(|_| {
+            error_response(
+                StatusCode::BAD_REQUEST,
+                format!("Invalid base64 in seeds[{}]", index),
+            )
+        })?,
+    };
+
+    if bytes.len() > MAX_SEED_LEN {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("seeds[{}] exceeds {} bytes", index, MAX_SEED_LEN),
+        ));
+    }
+    Ok(bytes)
+}
+
+#[derive(Deserialize, ToSchema)]
+struct FindAllPdasRequest {
+    program_id: String,
+    seeds: Vec<SeedInput>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct PdaEntry {
+    address: String,
+    bump: u8,
+    canonical: bool,
+}
+
+#[utoipa::path(
+    post,
+    path = "/address/find-all-pdas-for-seeds",
+    tag = "address",
+    request_body = FindAllPdasRequest,
+    responses(
+        (status = 200, body = SuccessResponse<Vec<PdaEntry>>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn find_all_pdas_for_seeds(
+    Json(payload): Json<FindAllPdasRequest>,
+) -> Result<Json<SuccessResponse<Vec<PdaEntry>>>, (StatusCode, Json<ErrorResponse>)> {
+    let program_id = parse_pubkey(&payload.program_id, "program_id")?;
+
+    // The bump takes up one of the seed slots
+    if payload.seeds.len() >= MAX_SEEDS {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Too many seeds; maximum is {}", MAX_SEEDS - 1),
+        ));
+    }
+    let seeds = payload
+        .seeds
+        .iter()
+        .enumerate()
+        .map(|(index, seed)| decode_seed(index, seed))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // find_program_address walks from 255 downwards, so the first hit is the canonical bump
+    let mut pdas: Vec<PdaEntry> = Vec::new();
+    for bump in (0..=u8::MAX).rev() {
+        let bump_seed = [bump];
+        let mut seed_refs: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+        seed_refs.push(&bump_seed);
+
+        if let Ok(address) = Pubkey::create_program_address(&seed_refs, &program_id) {
+            pdas.push(PdaEntry {
+                address: address.to_string(),
+                bump,
+                canonical: pdas.is_empty(),
+            });
+        }
+    }
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: pdas,
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct BuildTransactionRequest {
+    secret: String,
+    recent_blockhash: String,
+    instructions: Vec<InstructionData>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct BuiltTransaction {
+    transaction: String,
+    signature: String,
+}
+
+fn sign_transaction(
+    instructions: &[Instruction],
+    keypair: &Keypair,
+    recent_blockhash: Hash,
+) -> Result<BuiltTransaction, (StatusCode, Json<ErrorResponse>)> {
+    let mut transaction = Transaction::new_with_payer(instructions, Some(&keypair.pubkey()));
+    transaction
+        .try_sign(&[keypair], recent_blockhash)
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, format!("Failed to sign transaction: {}", e)))?;
+
+    let wire_bytes = bincode::serialize(&transaction).map_err(|e| {
+        error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to serialize transaction: {}", e),
+        )
+    })?;
+
+    Ok(BuiltTransaction {
+        transaction: general_purpose::STANDARD.encode(wire_bytes),
+        signature: transaction.signatures[0].to_string(),
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "/transaction/build",
+    tag = "transaction",
+    request_body = BuildTransactionRequest,
+    responses(
+        (status = 200, body = SuccessResponse<BuiltTransaction>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+    )
+)]
+async fn build_transaction(
+    Json(payload): Json<BuildTransactionRequest>,
+) -> Result<Json<SuccessResponse<BuiltTransaction>>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = parse_keypair(&payload.secret)?;
+    let recent_blockhash = parse_blockhash(&payload.recent_blockhash)?;
+
+    if payload.instructions.is_empty() {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "At least one instruction is required",
+        ));
+    }
+    let instructions = payload
+        .instructions
+        .iter()
+        .enumerate()
+        .map(|(index, data)| parse_instruction(index, data))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: sign_transaction(&instructions, &keypair, recent_blockhash)?,
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct SendTransactionRequest {
+    transaction: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct SendTransactionResponse {
+    signature: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/transaction/send",
+    tag = "transaction",
+    request_body = SendTransactionRequest,
+    responses(
+        (status = 200, body = SuccessResponse<SendTransactionResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn send_transaction(
+    State(state): State<AppState>,
+    Json(payload): Json<SendTransactionRequest>,
+) -> Result<Json<SuccessResponse<SendTransactionResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let transaction = decode_transaction(&payload.transaction)?;
+
+    let signature = rpc_call(&state, move |rpc| {
+        rpc.send_and_confirm_transaction(&transaction)
+    })
+    .await?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: SendTransactionResponse {
+            signature: signature.to_string(),
+        },
+    }))
+}
+
+#[derive(Serialize, ToSchema)]
+struct BlockhashResponse {
+    blockhash: String,
+    last_valid_block_height: u64,
+}
+
+#[utoipa::path(
+    get,
+    path = "/blockhash",
+    tag = "rpc",
+    responses(
+        (status = 200, body = SuccessResponse<BlockhashResponse>),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_blockhash(
+    State(state): State<AppState>,
+) -> Result<Json<SuccessResponse<BlockhashResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let (blockhash, last_valid_block_height) = rpc_call(&state, |rpc| {
+        rpc.get_latest_blockhash_with_commitment(rpc.commitment())
+    })
+    .await?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: BlockhashResponse {
+            blockhash: blockhash.to_string(),
+            last_valid_block_height,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct CreateTokenAccountRequest {
+    owner: String,
+    mint: String,
+    payer: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct CreateTokenAccountResponse {
+    associated_token_account: String,
+    #[serde(flatten)]
+    instruction: InstructionData,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/account/create",
+    tag = "token",
+    request_body = CreateTokenAccountRequest,
+    responses(
+        (status = 200, body = SuccessResponse<CreateTokenAccountResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn create_token_account(
+    Json(payload): Json<CreateTokenAccountRequest>,
+) -> Result<Json<SuccessResponse<CreateTokenAccountResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let owner = parse_pubkey(&payload.owner, "owner")?;
+    let mint = parse_pubkey(&payload.mint, "mint")?;
+    let payer = parse_pubkey(&payload.payer, "payer")?;
+
+    let associated_token_account =
+        get_associated_token_address_with_program_id(&owner, &mint, &spl_token::ID);
+    let ix = ata_instruction::create_associated_token_account(
+        &payer,
+        &owner,
+        &mint,
+        &spl_token::ID,
+    );
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: CreateTokenAccountResponse {
+            associated_token_account: associated_token_account.to_string(),
+            instruction: instruction_data(ix),
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct BurnTokenRequest {
+    mint: String,
+    // The token account holding the tokens to burn, not the owner's wallet address
+    account: String,
+    authority: String,
+    amount: u64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/burn",
+    tag = "token",
+    request_body = BurnTokenRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn burn_token(
+    Json(payload): Json<BurnTokenRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let mint = parse_pubkey(&payload.mint, "mint")?;
+    let account = parse_pubkey(&payload.account, "account")?;
+    let authority = parse_pubkey(&payload.authority, "authority")?;
+
+    if payload.amount == 0 {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "amount must be greater than 0",
+        ));
+    }
+
+    let ix = token_instruction::burn(
+        &spl_token::ID,
+        &account,
+        &mint,
+        &authority,
+        &[],
+        payload.amount,
+    )
+    .map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to create instruction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(ix),
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct CloseTokenAccountRequest {
+    account: String,
+    destination: String,
+    authority: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/close",
+    tag = "token",
+    request_body = CloseTokenAccountRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn close_token_account(
+    Json(payload): Json<CloseTokenAccountRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let account = parse_pubkey(&payload.account, "account")?;
+    let destination = parse_pubkey(&payload.destination, "destination")?;
+    let authority = parse_pubkey(&payload.authority, "authority")?;
+
+    let ix = token_instruction::close_account(
+        &spl_token::ID,
+        &account,
+        &destination,
+        &authority,
+        &[],
+    )
+    .map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to create instruction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(ix),
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct FreezeAccountRequest {
+    account: String,
+    mint: String,
+    freeze_authority: String,
+}
+
+type FreezeInstructionFn = fn(
+    &Pubkey,
+    &Pubkey,
+    &Pubkey,
+    &Pubkey,
+    &[&Pubkey],
+) -> Result<Instruction, solana_sdk::program_error::ProgramError>;
+
+// freeze_account and thaw_account share a signature, so both routes go through here.
+fn freeze_instruction(
+    payload: FreezeAccountRequest,
+    build: FreezeInstructionFn,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let account = parse_pubkey(&payload.account, "account")?;
+    let mint = parse_pubkey(&payload.mint, "mint")?;
+    let freeze_authority = parse_pubkey(&payload.freeze_authority, "freeze_authority")?;
+
+    let ix = build(&spl_token::ID, &account, &mint, &freeze_authority, &[]).map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to create instruction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(ix),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/freeze",
+    tag = "token",
+    request_body = FreezeAccountRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn freeze_token_account(
+    Json(payload): Json<FreezeAccountRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    freeze_instruction(payload, token_instruction::freeze_account)
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/thaw",
+    tag = "token",
+    request_body = FreezeAccountRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn thaw_token_account(
+    Json(payload): Json<FreezeAccountRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    freeze_instruction(payload, token_instruction::thaw_account)
+}
+
+#[derive(Deserialize, ToSchema)]
+struct ApproveRequest {
+    source: String,
+    delegate: String,
+    owner: String,
+    amount: u64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/approve",
+    tag = "token",
+    request_body = ApproveRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn approve_delegate(
+    Json(payload): Json<ApproveRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let source = parse_pubkey(&payload.source, "source")?;
+    let delegate = parse_pubkey(&payload.delegate, "delegate")?;
+    let owner = parse_pubkey(&payload.owner, "owner")?;
+
+    if payload.amount == 0 {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "amount must be greater than 0",
+        ));
+    }
+
+    let ix = token_instruction::approve(
+        &spl_token::ID,
+        &source,
+        &delegate,
+        &owner,
+        &[],
+        payload.amount,
+    )
+    .map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to create instruction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(ix),
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct RevokeRequest {
+    source: String,
+    owner: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/revoke",
+    tag = "token",
+    request_body = RevokeRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn revoke_delegate(
+    Json(payload): Json<RevokeRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let source = parse_pubkey(&payload.source, "source")?;
+    let owner = parse_pubkey(&payload.owner, "owner")?;
+
+    let ix = token_instruction::revoke(&spl_token::ID, &source, &owner, &[]).map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to create instruction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(ix),
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct DerivePdaRequest {
+    program_id: String,
+    // Each seed is a base64-encoded byte array
+    seeds: Vec<String>,
+    bump: Option<u8>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct DerivePdaResponse {
+    address: String,
+    bump: u8,
+}
+
+#[utoipa::path(
+    post,
+    path = "/pda/derive",
+    tag = "address",
+    request_body = DerivePdaRequest,
+    responses(
+        (status = 200, body = SuccessResponse<DerivePdaResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn derive_pda(
+    Json(payload): Json<DerivePdaRequest>,
+) -> Result<Json<SuccessResponse<DerivePdaResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let program_id = parse_pubkey(&payload.program_id, "program_id")?;
+
+    // The bump takes up one of the seed slots
+    if payload.seeds.len() >= MAX_SEEDS {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Too many seeds; maximum is {}", MAX_SEEDS - 1),
+        ));
+    }
+    let seeds = payload
+        .seeds
+        .iter()
+        .enumerate()
+        .map(|(index, seed)| decode_seed(index, &SeedInput::Base64(seed.clone())))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut seed_refs: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+
+    let (address, bump) = match payload.bump {
+        Some(bump) => {
+            let bump_seed = [bump];
+            seed_refs.push(&bump_seed);
+            let address = Pubkey::create_program_address(&seed_refs, &program_id).map_err(|e| {
+                error_response(
+                    StatusCode::BAD_REQUEST,
+                    format!("Failed to create program address with bump {}: {}", bump, e),
+                )
+            })?;
+            (address, bump)
+        }
+        None => Pubkey::try_find_program_address(&seed_refs, &program_id).ok_or_else(|| {
+            error_response(
+                StatusCode::BAD_REQUEST,
+                "No valid bump found for the given seeds",
+            )
+        })?,
+    };
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: DerivePdaResponse {
+            address: address.to_string(),
+            bump,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct SimulateTransactionRequest {
+    transaction: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct SimulateTransactionResponse {
+    #[schema(value_type = Option<Object>)]
+    err: Option<TransactionError>,
+    logs: Option<Vec<String>>,
+    units_consumed: Option<u64>,
+    #[schema(value_type = Option<Vec<Option<Object>>>)]
+    accounts: Option<Vec<Option<UiAccount>>>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/transaction/simulate",
+    tag = "transaction",
+    request_body = SimulateTransactionRequest,
+    responses(
+        (status = 200, body = SuccessResponse<SimulateTransactionResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn simulate_transaction(
+    State(state): State<AppState>,
+    Json(payload): Json<SimulateTransactionRequest>,
+) -> Result<Json<SuccessResponse<SimulateTransactionResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let transaction = decode_transaction(&payload.transaction)?;
+
+    let result = rpc_call(&state, move |rpc| rpc.simulate_transaction(&transaction))
+        .await?
+        .value;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: SimulateTransactionResponse {
+            err: result.err,
+            logs: result.logs,
+            units_consumed: result.units_consumed,
+            accounts: result.accounts,
+        },
+    }))
+}
+
+#[derive(Serialize, ToSchema)]
+struct AccountInfoResponse {
+    lamports: u64,
+    owner: String,
+    executable: bool,
+    rent_epoch: u64,
+    data: String,
+}
+
+#[utoipa::path(
+    get,
+    path = "/account/{pubkey}",
+    tag = "account",
+    params(
+        ("pubkey" = String, Path),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<AccountInfoResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_account_info(
+    State(state): State<AppState>,
+    Path(pubkey): Path<String>,
+) -> Result<Json<SuccessResponse<AccountInfoResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let pubkey = parse_pubkey(&pubkey, "account")?;
+    let account = fetch_account(&state, pubkey).await?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: AccountInfoResponse {
+            lamports: account.lamports,
+            owner: account.owner.to_string(),
+            executable: account.executable,
+            rent_epoch: account.rent_epoch,
+            data: general_purpose::STANDARD.encode(&account.data),
+        },
+    }))
+}
+
+#[derive(Serialize, ToSchema)]
+struct TokenBalanceResponse {
+    amount: String,
+    decimals: u8,
+    ui_amount: Option<f64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/token/balance/{account}",
+    tag = "token",
+    params(
+        ("account" = String, Path),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<TokenBalanceResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Account is not a valid token account", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_token_balance(
+    State(state): State<AppState>,
+    Path(account): Path<String>,
+) -> Result<Json<SuccessResponse<TokenBalanceResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let account = parse_pubkey(&account, "account")?;
+
+    // Missing and non-token accounts both come back as invalid-params errors, which mean 404 here
+    let balance = rpc_pool_call(&state, move |rpc| rpc.get_token_account_balance(&account))
+        .await?
+        .map_err(|e| match e {
+            RpcPoolError::Failed(ref error)
+                if matches!(
+                    error.kind(),
+                    ClientErrorKind::RpcError(RpcError::RpcResponseError { code: -32602, .. })
+                ) =>
+            {
+                error_response(StatusCode::NOT_FOUND, "Account is not a valid token account")
+            }
+            e => rpc_error_response(e),
+        })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: TokenBalanceResponse {
+            amount: balance.amount,
+            decimals: balance.decimals,
+            ui_amount: balance.ui_amount,
+        },
+    }))
+}
+
+const MAX_AIRDROP_LAMPORTS: u64 = 2_000_000_000;
+
+#[derive(Deserialize, ToSchema)]
+struct AirdropRequest {
+    pubkey: String,
+    lamports: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+struct AirdropResponse {
+    signature: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/airdrop",
+    tag = "rpc",
+    request_body = AirdropRequest,
+    responses(
+        (status = 200, body = SuccessResponse<AirdropResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 403, description = "Airdrops are disabled on mainnet-beta", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn request_airdrop(
+    State(state): State<AppState>,
+    Json(payload): Json<AirdropRequest>,
+) -> Result<Json<SuccessResponse<AirdropResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    // SOLANA_NETWORK may be left at its devnet default while the RPC URLs point at mainnet
+    let targets_mainnet = state.config.solana_network == SolanaNetwork::MainnetBeta
+        || state
+            .config
+            .rpc_urls()
+            .iter()
+            .any(|url| url.contains("mainnet-beta"));
+    if targets_mainnet {
+        return Err(error_response(
+            StatusCode::FORBIDDEN,
+            "Airdrop not available on mainnet",
+        ));
+    }
+
+    let pubkey = parse_pubkey(&payload.pubkey, "pubkey")?;
+    if payload.lamports > MAX_AIRDROP_LAMPORTS {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("lamports exceeds the airdrop limit of {}", MAX_AIRDROP_LAMPORTS),
+        ));
+    }
+
+    let signature = rpc_call(&state, move |rpc| {
+        rpc.request_airdrop(&pubkey, payload.lamports)
+    })
+    .await?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: AirdropResponse {
+            signature: signature.to_string(),
+        },
+    }))
+}
+
+const MAX_BATCH_INSTRUCTIONS: usize = 20;
+
+// Same as /transaction/build, but capped so bundles stay well inside the transaction size limit.
+#[utoipa::path(
+    post,
+    path = "/transaction/batch",
+    tag = "transaction",
+    request_body = BuildTransactionRequest,
+    responses(
+        (status = 200, body = SuccessResponse<BuiltTransaction>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+    )
+)]
+async fn batch_transaction(
+    Json(payload): Json<BuildTransactionRequest>,
+) -> Result<Json<SuccessResponse<BuiltTransaction>>, (StatusCode, Json<ErrorResponse>)> {
+    if payload.instructions.len() > MAX_BATCH_INSTRUCTIONS {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Too many instructions; maximum is {}", MAX_BATCH_INSTRUCTIONS),
+        ));
+    }
+
+    build_transaction(Json(payload)).await
+}
+
+#[derive(Deserialize, ToSchema)]
+struct TransferCheckedRequest {
+    source: String,
+    mint: String,
+    destination: String,
+    owner: String,
+    amount: u64,
+    decimals: u8,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/transfer-checked",
+    tag = "token",
+    request_body = TransferCheckedRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn transfer_checked(
+    Json(payload): Json<TransferCheckedRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let source = parse_pubkey(&payload.source, "source")?;
+    let mint = parse_pubkey(&payload.mint, "mint")?;
+    let destination = parse_pubkey(&payload.destination, "destination")?;
+    let owner = parse_pubkey(&payload.owner, "owner")?;
+
+    if payload.amount == 0 {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "amount must be greater than 0",
+        ));
+    }
+
+    let ix = token_instruction::transfer_checked(
+        &spl_token::ID,
+        &source,
+        &mint,
+        &destination,
+        &owner,
+        &[],
+        payload.amount,
+        payload.decimals,
+    )
+    .map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to create instruction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(ix),
+    }))
+}
+
+const MEMO_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+const MAX_MEMO_LEN: usize = 566;
+
+#[derive(Deserialize, ToSchema)]
+struct MemoRequest {
+    memo: String,
+    signers: Vec<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/memo",
+    tag = "transaction",
+    request_body = MemoRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn build_memo(
+    Json(payload): Json<MemoRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    if payload.memo.len() > MAX_MEMO_LEN {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "memo is {} bytes; the maximum is {} bytes",
+                payload.memo.len(),
+                MAX_MEMO_LEN
+            ),
+        ));
+    }
+
+    let accounts = payload
+        .signers
+        .iter()
+        .enumerate()
+        .map(|(index, signer)| {
+            parse_pubkey(signer, &format!("signers[{}]", index))
+                .map(|pubkey| AccountMeta::new_readonly(pubkey, true))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let ix = Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts,
+        data: payload.memo.into_bytes(),
+    };
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(ix),
+    }))
+}
+
+const DEFAULT_DERIVATION_PATH: &str = "m/44'/501'/0'/0'";
+
+#[derive(Deserialize, ToSchema)]
+struct KeypairFromMnemonicRequest {
+    mnemonic: String,
+    passphrase: Option<String>,
+    derivation_path: Option<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/keypair/from-mnemonic",
+    tag = "keypair",
+    request_body = KeypairFromMnemonicRequest,
+    responses(
+        (status = 200, body = SuccessResponse<KeypairData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn keypair_from_mnemonic(
+    Json(payload): Json<KeypairFromMnemonicRequest>,
+) -> Result<Json<SuccessResponse<KeypairData>>, (StatusCode, Json<ErrorResponse>)> {
+    let mnemonic = Mnemonic::from_phrase(payload.mnemonic.trim(), Language::English)
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, format!("Invalid mnemonic: {}", e)))?;
+    let derivation_path = DerivationPath::from_absolute_path_str(
+        payload.derivation_path.as_deref().unwrap_or(DEFAULT_DERIVATION_PATH),
+    )
+    .map_err(|e| error_response(StatusCode::BAD_REQUEST, format!("Invalid derivation_path: {}", e)))?;
+
+    let seed = Seed::new(&mnemonic, payload.passphrase.as_deref().unwrap_or(""));
+    let keypair = keypair_from_seed_and_derivation_path(seed.as_bytes(), Some(derivation_path))
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, format!("Failed to derive keypair: {}", e)))?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: KeypairData {
+            pubkey: keypair.pubkey().to_string(),
+            secret: bs58::encode(keypair.to_bytes()).into_string(),
+        },
+    }))
+}
+
+const MAX_VANITY_LEN: usize = 6;
+const MAX_VANITY_TIMEOUT_SECS: u64 = 30;
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+fn default_vanity_timeout() -> u64 {
+    MAX_VANITY_TIMEOUT_SECS
+}
+
+// Set when the owning request future is dropped, e.g. because the client disconnected.
+struct CancelOnDrop(Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+#[derive(Deserialize, ToSchema)]
+struct VanityKeypairRequest {
+    prefix: Option<String>,
+    suffix: Option<String>,
+    #[serde(default)]
+    case_sensitive: bool,
+    #[serde(default = "default_vanity_timeout")]
+    timeout_seconds: u64,
+}
+
+// Rejects patterns that no base58 address could ever match, which would otherwise spin until timeout.
+fn validate_vanity_pattern(pattern: &str, field: &str, case_sensitive: bool) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let matchable = |c: char| {
+        BASE58_ALPHABET.contains(c)
+            || (!case_sensitive
+                && (BASE58_ALPHABET.contains(c.to_ascii_lowercase())
+                    || BASE58_ALPHABET.contains(c.to_ascii_uppercase())))
+    };
+    match pattern.chars().find(|c| !matchable(*c)) {
+        Some(c) => Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("{} contains '{}', which never appears in a base58 address", field, c),
+        )),
+        None => Ok(()),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/keypair/vanity",
+    tag = "keypair",
+    request_body = VanityKeypairRequest,
+    responses(
+        (status = 200, body = SuccessResponse<KeypairData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 408, description = "No matching keypair found before the timeout", body = ErrorResponse),
+        (status = 500, description = "Search worker failed", body = ErrorResponse),
+    )
+)]
+async fn vanity_keypair(
+    Json(payload): Json<VanityKeypairRequest>,
+) -> Result<Json<SuccessResponse<KeypairData>>, (StatusCode, Json<ErrorResponse>)> {
+    let prefix = payload.prefix.unwrap_or_default();
+    let suffix = payload.suffix.unwrap_or_default();
+    let case_sensitive = payload.case_sensitive;
+
+    if prefix.is_empty() && suffix.is_empty() {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "At least one of prefix or suffix is required",
+        ));
+    }
+    if prefix.chars().count() + suffix.chars().count() > MAX_VANITY_LEN {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("prefix and suffix may be at most {} characters combined", MAX_VANITY_LEN),
+        ));
+    }
+    validate_vanity_pattern(&prefix, "prefix", case_sensitive)?;
+    validate_vanity_pattern(&suffix, "suffix", case_sensitive)?;
+    if payload.timeout_seconds == 0 || payload.timeout_seconds > MAX_VANITY_TIMEOUT_SECS {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("timeout_seconds must be between 1 and {}", MAX_VANITY_TIMEOUT_SECS),
+        ));
+    }
+
+    // The blocking search checks the deadline and cancel flag itself so it never outlives the request
+    let deadline = Instant::now() + Duration::from_secs(payload.timeout_seconds);
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let _cancel_guard = CancelOnDrop(cancelled.clone());
+    let keypair = tokio::task::spawn_blocking(move || {
+        let (prefix, suffix) = if case_sensitive {
+            (prefix, suffix)
+        } else {
+            (prefix.to_lowercase(), suffix.to_lowercase())
+        };
+        while Instant::now() < deadline && !cancelled.load(Ordering::Relaxed) {
+            let keypair = Keypair::new();
+            let mut address = keypair.pubkey().to_string();
+            if !case_sensitive {
+                address.make_ascii_lowercase();
+            }
+            if address.starts_with(&prefix) && address.ends_with(&suffix) {
+                return Some(keypair);
+            }
+        }
+        None
+    })
+    .await
+    .map_err(|e| {
+        error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Vanity search failed: {}", e),
+        )
+    })?
+    .ok_or_else(|| {
+        error_response(
+            StatusCode::REQUEST_TIMEOUT,
+            format!("No matching keypair found within {} seconds", payload.timeout_seconds),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: KeypairData {
+            pubkey: keypair.pubkey().to_string(),
+            secret: bs58::encode(keypair.to_bytes()).into_string(),
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct CreateAccountRequest {
+    from: String,
+    new_account: String,
+    lamports: u64,
+    space: u64,
+    owner: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/system/create-account",
+    tag = "system",
+    request_body = CreateAccountRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn create_account(
+    Json(payload): Json<CreateAccountRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let from = parse_pubkey(&payload.from, "from")?;
+    let new_account = parse_pubkey(&payload.new_account, "new_account")?;
+    let owner = parse_pubkey(&payload.owner, "owner")?;
+    check_account_space(payload.space)?;
+
+    let ix = system_instruction::create_account(
+        &from,
+        &new_account,
+        payload.lamports,
+        payload.space,
+        &owner,
+    );
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(ix),
+    }))
+}
+
+fn check_account_space(space: u64) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    if space > system_instruction::MAX_PERMITTED_DATA_LENGTH {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "space of {} bytes exceeds the {} byte account size limit",
+                space,
+                system_instruction::MAX_PERMITTED_DATA_LENGTH
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[derive(Deserialize, ToSchema)]
+struct AllocateRequest {
+    account: String,
+    space: u64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/system/allocate",
+    tag = "system",
+    request_body = AllocateRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn allocate_account(
+    Json(payload): Json<AllocateRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let account = parse_pubkey(&payload.account, "account")?;
+    check_account_space(payload.space)?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(system_instruction::allocate(&account, payload.space)),
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct AssignRequest {
+    account: String,
+    owner: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/system/assign",
+    tag = "system",
+    request_body = AssignRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn assign_account(
+    Json(payload): Json<AssignRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let account = parse_pubkey(&payload.account, "account")?;
+    let owner = parse_pubkey(&payload.owner, "owner")?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(system_instruction::assign(&account, &owner)),
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct TransferWithSeedRequest {
+    from: String,
+    // The signer the derived `from` address was created from
+    from_base: String,
+    from_seed: String,
+    from_owner: String,
+    to: String,
+    lamports: u64,
+}
+
+#[utoipa::path(
+    post,
+    path = "/system/transfer-with-seed",
+    tag = "system",
+    request_body = TransferWithSeedRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn transfer_with_seed(
+    Json(payload): Json<TransferWithSeedRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let from = parse_pubkey(&payload.from, "from")?;
+    let from_base = parse_pubkey(&payload.from_base, "from_base")?;
+    let from_owner = parse_pubkey(&payload.from_owner, "from_owner")?;
+    let to = parse_pubkey(&payload.to, "to")?;
+
+    if payload.lamports == 0 {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "lamports must be greater than 0",
+        ));
+    }
+
+    // The runtime rejects a mismatch anyway; catching it here gives a clearer error
+    let derived = Pubkey::create_with_seed(&from_base, &payload.from_seed, &from_owner)
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, format!("Invalid from_seed: {}", e)))?;
+    if derived != from {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "from does not match the address derived from from_base, from_seed and from_owner ({})",
+                derived
+            ),
+        ));
+    }
+
+    let ix = system_instruction::transfer_with_seed(
+        &from,
+        &from_base,
+        payload.from_seed,
+        &from_owner,
+        &to,
+        payload.lamports,
+    );
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(ix),
+    }))
+}
+
+const SUPPORTED_MINT_EXTENSIONS: &[&str] = &[
+    "transfer_fee",
+    "non_transferable",
+    "mint_close_authority",
+    "permanent_delegate",
+    "interest_bearing",
+    "metadata_pointer",
+];
+
+// Extensions that need configuration start out with the mint authority in control and zeroed
+// fees/rates, which the authority can adjust after the mint exists.
+fn mint_extension_instruction(
+    name: &str,
+    mint: &Pubkey,
+    authority: &Pubkey,
+) -> Option<(ExtensionType, Result<Instruction, solana_sdk::program_error::ProgramError>)> {
+    let program_id = &spl_token_2022::ID;
+    let extension = match name {
+        "transfer_fee" => (
+            ExtensionType::TransferFeeConfig,
+            transfer_fee::instruction::initialize_transfer_fee_config(
+                program_id,
+                mint,
+                Some(authority),
+                Some(authority),
+                0,
+                0,
+            ),
+        ),
+        "non_transferable" => (
+            ExtensionType::NonTransferable,
+            spl_token_2022::instruction::initialize_non_transferable_mint(program_id, mint),
+        ),
+        "mint_close_authority" => (
+            ExtensionType::MintCloseAuthority,
+            spl_token_2022::instruction::initialize_mint_close_authority(program_id, mint, Some(authority)),
+        ),
+        "permanent_delegate" => (
+            ExtensionType::PermanentDelegate,
+            spl_token_2022::instruction::initialize_permanent_delegate(program_id, mint, authority),
+        ),
+        "interest_bearing" => (
+            ExtensionType::InterestBearingConfig,
+            interest_bearing_mint::instruction::initialize(program_id, mint, Some(*authority), 0),
+        ),
+        "metadata_pointer" => (
+            ExtensionType::MetadataPointer,
+            metadata_pointer::instruction::initialize(program_id, mint, Some(*authority), Some(*mint)),
+        ),
+        _ => return None,
+    };
+    Some(extension)
+}
+
+#[derive(Deserialize, ToSchema)]
+struct CreateToken2022Request {
+    mint: String,
+    mint_authority: String,
+    decimals: u8,
+    #[serde(default)]
+    extensions: Vec<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct CreateToken2022Response {
+    // initialize_mint2, which must run after every extension instruction
+    #[serde(flatten)]
+    instruction: InstructionData,
+    extension_instructions: Vec<InstructionData>,
+    // Bytes to allocate for the mint account so the chosen extensions fit
+    space: usize,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/create-2022",
+    tag = "token",
+    request_body = CreateToken2022Request,
+    responses(
+        (status = 200, body = SuccessResponse<CreateToken2022Response>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn create_token_2022(
+    Json(payload): Json<CreateToken2022Request>,
+) -> Result<Json<SuccessResponse<CreateToken2022Response>>, (StatusCode, Json<ErrorResponse>)> {
+    let mint = parse_pubkey(&payload.mint, "mint")?;
+    let mint_authority = parse_pubkey(&payload.mint_authority, "mint_authority")?;
+
+    let mut extension_types = Vec::new();
+    let mut extension_instructions = Vec::new();
+    for name in &payload.extensions {
+        let (extension_type, ix) = mint_extension_instruction(name, &mint, &mint_authority)
+            .ok_or_else(|| {
+                error_response(
+                    StatusCode::BAD_REQUEST,
+                    format!(
+                        "Unsupported extension '{}'; supported extensions are: {}",
+                        name,
+                        SUPPORTED_MINT_EXTENSIONS.join(", ")
+                    ),
+                )
+            })?;
+        if extension_types.contains(&extension_type) {
+            return Err(error_response(
+                StatusCode::BAD_REQUEST,
+                format!("Extension '{}' is listed more than once", name),
+            ));
+        }
+        let ix = ix.map_err(|e| {
+            error_response(
+                StatusCode::BAD_REQUEST,
+                format!("Failed to create {} instruction: {}", name, e),
+            )
+        })?;
+        extension_types.push(extension_type);
+        extension_instructions.push(instruction_data(ix));
+    }
+
+    let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(&extension_types)
+        .map_err(|e| {
+            error_response(
+                StatusCode::BAD_REQUEST,
+                format!("Failed to calculate mint size: {}", e),
+            )
+        })?;
+    let ix = spl_token_2022::instruction::initialize_mint2(
+        &spl_token_2022::ID,
+        &mint,
+        &mint_authority,
+        None,
+        payload.decimals,
+    )
+    .map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to create instruction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: CreateToken2022Response {
+            instruction: instruction_data(ix),
+            extension_instructions,
+            space,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct DecodeTransactionRequest {
+    transaction: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct DecodedInstruction {
+    program_id_index: u8,
+    accounts: Vec<u8>,
+    data: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct DecodedMessage {
+    account_keys: Vec<String>,
+    recent_blockhash: String,
+    instructions: Vec<DecodedInstruction>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct DecodedTransaction {
+    signatures: Vec<String>,
+    message: DecodedMessage,
+}
+
+#[utoipa::path(
+    post,
+    path = "/transaction/decode",
+    tag = "transaction",
+    request_body = DecodeTransactionRequest,
+    responses(
+        (status = 200, body = SuccessResponse<DecodedTransaction>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn decode_transaction_details(
+    Json(payload): Json<DecodeTransactionRequest>,
+) -> Result<Json<SuccessResponse<DecodedTransaction>>, (StatusCode, Json<ErrorResponse>)> {
+    let transaction = decode_transaction(&payload.transaction)?;
+    let message = transaction.message;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: DecodedTransaction {
+            signatures: transaction
+                .signatures
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            message: DecodedMessage {
+                account_keys: message.account_keys.iter().map(ToString::to_string).collect(),
+                recent_blockhash: message.recent_blockhash.to_string(),
+                instructions: message
+                    .instructions
+                    .into_iter()
+                    .map(|ix| DecodedInstruction {
+                        program_id_index: ix.program_id_index,
+                        accounts: ix.accounts,
+                        data: general_purpose::STANDARD.encode(&ix.data),
+                    })
+                    .collect(),
+            },
+        },
+    }))
+}
+
+/// An address lookup table the compiler may load accounts from. Which entries it uses is decided
+/// when the message is compiled and reported in `address_table_lookups`.
+#[derive(Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+struct LookupTableInput {
+    account: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct AddressTableLookup {
+    account: String,
+    writable_indexes: Vec<u8>,
+    readonly_indexes: Vec<u8>,
+}
+
+#[derive(Deserialize, ToSchema)]
+struct BuildV0TransactionRequest {
+    secret: String,
+    recent_blockhash: String,
+    instructions: Vec<InstructionData>,
+    #[serde(default)]
+    lookup_tables: Vec<LookupTableInput>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct BuiltV0Transaction {
+    #[serde(flatten)]
+    transaction: BuiltTransaction,
+    /// The table entries the compiled message loads, which may cover only some of the tables supplied
+    address_table_lookups: Vec<AddressTableLookup>,
+}
+
+// Message compilation needs each table's address list, so the tables are loaded from RPC.
+async fn fetch_lookup_table(
+    state: &AppState,
+    index: usize,
+    input: &LookupTableInput,
+) -> Result<AddressLookupTableAccount, (StatusCode, Json<ErrorResponse>)> {
+    let key = parse_pubkey(&input.account, &format!("lookup_tables[{}].account", index))?;
+    let account = fetch_account(state, key).await?;
+    if account.owner != address_lookup_table::program::ID {
+        return Err(error_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("lookup_tables[{}] is not an address lookup table", index),
+        ));
+    }
+    let table = AddressLookupTable::deserialize(&account.data).map_err(|_| {
+        error_response(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("lookup_tables[{}] is not a valid address lookup table", index),
+        )
+    })?;
+
+    Ok(AddressLookupTableAccount {
+        key,
+        addresses: table.addresses.into_owned(),
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "/transaction/build-v0",
+    tag = "transaction",
+    request_body = BuildV0TransactionRequest,
+    responses(
+        (status = 200, body = SuccessResponse<BuiltV0Transaction>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Lookup table account not found", body = ErrorResponse),
+        (status = 422, description = "Account is not an address lookup table", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn build_v0_transaction(
+    State(state): State<AppState>,
+    Json(payload): Json<BuildV0TransactionRequest>,
+) -> Result<Json<SuccessResponse<BuiltV0Transaction>>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = parse_keypair(&payload.secret)?;
+    let recent_blockhash = parse_blockhash(&payload.recent_blockhash)?;
+
+    if payload.instructions.is_empty() {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "At least one instruction is required",
+        ));
+    }
+    let instructions = payload
+        .instructions
+        .iter()
+        .enumerate()
+        .map(|(index, data)| parse_instruction(index, data))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut lookup_tables = Vec::with_capacity(payload.lookup_tables.len());
+    for (index, input) in payload.lookup_tables.iter().enumerate() {
+        lookup_tables.push(fetch_lookup_table(&state, index, input).await?);
+    }
+
+    let message = v0::Message::try_compile(
+        &keypair.pubkey(),
+        &instructions,
+        &lookup_tables,
+        recent_blockhash,
+    )
+    .map_err(|e| error_response(StatusCode::BAD_REQUEST, format!("Failed to compile message: {}", e)))?;
+    let address_table_lookups = message
+        .address_table_lookups
+        .iter()
+        .map(|lookup| AddressTableLookup {
+            account: lookup.account_key.to_string(),
+            writable_indexes: lookup.writable_indexes.clone(),
+            readonly_indexes: lookup.readonly_indexes.clone(),
+        })
+        .collect();
+
+    let transaction = VersionedTransaction::try_new(VersionedMessage::V0(message), &[&keypair])
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, format!("Failed to sign transaction: {}", e)))?;
+    let wire_bytes = bincode::serialize(&transaction).map_err(|e| {
+        error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to serialize transaction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: BuiltV0Transaction {
+            transaction: BuiltTransaction {
+                transaction: general_purpose::STANDARD.encode(wire_bytes),
+                signature: transaction.signatures[0].to_string(),
+            },
+            address_table_lookups,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct CreateStakeRequest {
+    from: String,
+    stake_account: String,
+    authorized_staker: String,
+    authorized_withdrawer: String,
+    // Total funding for the new account, including its rent-exempt reserve
+    lamports: u64,
+}
+
+#[derive(Serialize, ToSchema)]
+struct StakeInstructionsResponse {
+    instructions: Vec<InstructionData>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/stake/create",
+    tag = "stake",
+    request_body = CreateStakeRequest,
+    responses(
+        (status = 200, body = SuccessResponse<StakeInstructionsResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn create_stake(
+    Json(payload): Json<CreateStakeRequest>,
+) -> Result<Json<SuccessResponse<StakeInstructionsResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let from = parse_pubkey(&payload.from, "from")?;
+    let stake_account = parse_pubkey(&payload.stake_account, "stake_account")?;
+    let authorized_staker = parse_pubkey(&payload.authorized_staker, "authorized_staker")?;
+    let authorized_withdrawer = parse_pubkey(&payload.authorized_withdrawer, "authorized_withdrawer")?;
+
+    if payload.lamports == 0 {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "lamports must be greater than 0",
+        ));
+    }
+
+    // CreateAccount followed by Initialize
+    let instructions = stake_instruction::create_account(
+        &from,
+        &stake_account,
+        &Authorized {
+            staker: authorized_staker,
+            withdrawer: authorized_withdrawer,
+        },
+        &Lockup::default(),
+        payload.lamports,
+    );
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: StakeInstructionsResponse {
+            instructions: instructions.into_iter().map(instruction_data).collect(),
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct DelegateStakeRequest {
+    stake_account: String,
+    authorized: String,
+    vote_account: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/stake/delegate",
+    tag = "stake",
+    request_body = DelegateStakeRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn delegate_stake(
+    Json(payload): Json<DelegateStakeRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let stake_account = parse_pubkey(&payload.stake_account, "stake_account")?;
+    let authorized = parse_pubkey(&payload.authorized, "authorized")?;
+    let vote_account = parse_pubkey(&payload.vote_account, "vote_account")?;
+
+    let ix = stake_instruction::delegate_stake(&stake_account, &authorized, &vote_account);
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: instruction_data(ix),
+    }))
+}
+
+const MAX_BATCH_MESSAGES: usize = 100;
+
+#[derive(Deserialize, ToSchema)]
+struct SignBatchRequest {
+    secret: String,
+    messages: Vec<String>,
+    #[serde(default)]
+    encoding: SignatureEncoding,
+}
+
+#[utoipa::path(
+    post,
+    path = "/message/sign-batch",
+    tag = "message",
+    request_body = SignBatchRequest,
+    responses(
+        (status = 200, body = SuccessResponse<Vec<SignedMessage>>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn sign_message_batch(
+    Json(payload): Json<SignBatchRequest>,
+) -> Result<Json<SuccessResponse<Vec<SignedMessage>>>, (StatusCode, Json<ErrorResponse>)> {
+    if payload.messages.len() > MAX_BATCH_MESSAGES {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Too many messages; maximum is {}", MAX_BATCH_MESSAGES),
+        ));
+    }
+    let keypair = parse_keypair(&payload.secret)?;
+    let public_key = keypair.pubkey().to_string();
+
+    // Results stay in request order so callers can zip them back onto their messages
+    let signed = payload
+        .messages
+        .into_iter()
+        .map(|message| SignedMessage {
+            signature: payload
+                .encoding
+                .encode(keypair.sign_message(message.as_bytes()).as_ref()),
+            public_key: public_key.clone(),
+            message,
+        })
+        .collect();
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: signed,
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct VerifyBatchItem {
+    message: String,
+    signature: String,
+    pubkey: String,
+    encoding: Option<String>,
+}
+
+#[derive(Deserialize, ToSchema)]
+struct VerifyBatchRequest {
+    items: Vec<VerifyBatchItem>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct VerifyBatchResult {
+    valid: bool,
+    message: String,
+    pubkey: String,
+    error: Option<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/message/verify-batch",
+    tag = "message",
+    request_body = VerifyBatchRequest,
+    responses(
+        (status = 200, body = SuccessResponse<Vec<VerifyBatchResult>>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn verify_message_batch(
+    Json(payload): Json<VerifyBatchRequest>,
+) -> Result<Json<SuccessResponse<Vec<VerifyBatchResult>>>, (StatusCode, Json<ErrorResponse>)> {
+    if payload.items.len() > MAX_BATCH_MESSAGES {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Too many items; maximum is {}", MAX_BATCH_MESSAGES),
+        ));
+    }
+
+    // A malformed item is reported as invalid with its own error rather than failing the batch
+    let results = payload
+        .items
+        .into_iter()
+        .map(|item| {
+            let outcome = SignatureEncoding::parse(item.encoding.as_deref()).and_then(|encoding| {
+                verify_ed25519(&item.message, &item.signature, &item.pubkey, encoding)
+            });
+            let (valid, error) = match outcome {
+                Ok(valid) => (valid, None),
+                Err(error) => (false, Some(error)),
+            };
+            VerifyBatchResult {
+                valid,
+                message: item.message,
+                pubkey: item.pubkey,
+                error,
+            }
+        })
+        .collect();
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: results,
+    }))
+}
+
+#[derive(Serialize, ToSchema)]
+struct TokenInfoResponse {
+    mint_authority: Option<String>,
+    supply: u64,
+    decimals: u8,
+    is_initialized: bool,
+    freeze_authority: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/token/info/{mint}",
+    tag = "token",
+    params(
+        ("mint" = String, Path),
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<TokenInfoResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 404, description = "Account not found", body = ErrorResponse),
+        (status = 422, description = "Account is not a valid SPL mint", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_token_info(
+    State(state): State<AppState>,
+    Path(mint): Path<String>,
+) -> Result<Json<SuccessResponse<TokenInfoResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let mint = parse_pubkey(&mint, "mint")?;
+    let mint = unpack_mint(fetch_account(&state, mint).await?)?.base;
+    let mint_authority: Option<Pubkey> = mint.mint_authority.into();
+    let freeze_authority: Option<Pubkey> = mint.freeze_authority.into();
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: TokenInfoResponse {
+            mint_authority: mint_authority.map(|authority| authority.to_string()),
+            supply: mint.supply,
+            decimals: mint.decimals,
+            is_initialized: mint.is_initialized,
+            freeze_authority: freeze_authority.map(|authority| authority.to_string()),
+        },
+    }))
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct TokenAccountsQuery {
+    mint: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct OwnerTokenAccount {
+    pubkey: String,
+    mint: String,
+    amount: String,
+    decimals: u8,
+    delegate: Option<String>,
+    state: &'static str,
+}
+
+// The node returns these jsonParsed, so decode the parsed view rather than the raw bytes.
+fn owner_token_account(
+    keyed: RpcKeyedAccount,
+) -> Result<OwnerTokenAccount, (StatusCode, Json<ErrorResponse>)> {
+    let unexpected = || {
+        error_response(
+            StatusCode::BAD_GATEWAY,
+            format!("RPC returned an unparseable token account {}", keyed.pubkey),
+        )
+    };
+    let UiAccountData::Json(parsed) = &keyed.account.data else {
+        return Err(unexpected());
+    };
+    let TokenAccountType::Account(account) =
+        serde_json::from_value(parsed.parsed.clone()).map_err(|_| unexpected())?
+    else {
+        return Err(unexpected());
+    };
+
+    Ok(OwnerTokenAccount {
+        pubkey: keyed.pubkey,
+        mint: account.mint,
+        amount: account.token_amount.amount,
+        decimals: account.token_amount.decimals,
+        delegate: account.delegate,
+        state: match account.state {
+            UiAccountState::Uninitialized => "uninitialized",
+            UiAccountState::Initialized => "initialized",
+            UiAccountState::Frozen => "frozen",
+        },
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/token/accounts/{owner}",
+    tag = "token",
+    params(
+        ("owner" = String, Path),
+        TokenAccountsQuery,
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<Vec<OwnerTokenAccount>>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_token_accounts_by_owner(
+    State(state): State<AppState>,
+    Path(owner): Path<String>,
+    Query(query): Query<TokenAccountsQuery>,
+) -> Result<Json<SuccessResponse<Vec<OwnerTokenAccount>>>, (StatusCode, Json<ErrorResponse>)> {
+    let owner = parse_pubkey(&owner, "owner")?;
+    let mint = query
+        .mint
+        .as_deref()
+        .map(|mint| parse_pubkey(mint, "mint"))
+        .transpose()?;
+
+    let accounts = rpc_call(&state, move |rpc| {
+        let filter = match mint {
+            Some(mint) => TokenAccountsFilter::Mint(mint),
+            None => TokenAccountsFilter::ProgramId(spl_token::ID),
+        };
+        rpc.get_token_accounts_by_owner(&owner, filter)
+    })
+    .await?
+    .into_iter()
+    .map(owner_token_account)
+    .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: accounts,
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct TransactionFeeRequest {
+    transaction: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct TransactionFeeResponse {
+    fee_lamports: u64,
+    fee_sol: f64,
+}
+
+// This is the fee at the time of the query; it can change before the transaction is submitted.
+#[utoipa::path(
+    post,
+    path = "/transaction/fee",
+    tag = "transaction",
+    request_body = TransactionFeeRequest,
+    responses(
+        (status = 200, body = SuccessResponse<TransactionFeeResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+        (status = 502, description = "RPC node returned an error", body = ErrorResponse),
+        (status = 503, description = "No RPC node could be reached", body = ErrorResponse),
+    )
+)]
+async fn get_transaction_fee(
+    State(state): State<AppState>,
+    Json(payload): Json<TransactionFeeRequest>,
+) -> Result<Json<SuccessResponse<TransactionFeeResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let transaction = decode_transaction(&payload.transaction)?;
+
+    let fee_lamports =
+        rpc_call(&state, move |rpc| rpc.get_fee_for_message(&transaction.message)).await?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: TransactionFeeResponse {
+            fee_lamports,
+            fee_sol: lamports_to_sol(fee_lamports),
+        },
+    }))
+}
+
+// base58/base64 secrets arrive as strings, while the CLI's keypair files are a plain byte array.
+#[derive(Deserialize, ToSchema)]
+#[serde(untagged)]
+enum SecretKeyInput {
+    Encoded(String),
+    Bytes(Vec<u8>),
+}
+
+#[derive(Deserialize, ToSchema)]
+struct RecoverKeypairRequest {
+    bytes: SecretKeyInput,
+    format: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct RecoveredKeypair {
+    pubkey: String,
+    secret_base58: String,
+    secret_base64: String,
+    secret_json_array: Vec<u8>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/keypair/recover",
+    tag = "keypair",
+    request_body = RecoverKeypairRequest,
+    responses(
+        (status = 200, body = SuccessResponse<RecoveredKeypair>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn recover_keypair(
+    Json(payload): Json<RecoverKeypairRequest>,
+) -> Result<Json<SuccessResponse<RecoveredKeypair>>, (StatusCode, Json<ErrorResponse>)> {
+    let secret_bytes = match (payload.format.as_str(), payload.bytes) {
+        ("base58", SecretKeyInput::Encoded(value)) => bs58::decode(value)
+            .into_vec()
+            .map_err(|_| error_response(StatusCode::BAD_REQUEST, "Invalid base58 secret key"))?,
+        ("base64", SecretKeyInput::Encoded(value)) => general_purpose::STANDARD
+            .decode(value)
+            .map_err(|_| error_response(StatusCode::BAD_REQUEST, "Invalid base64 secret key"))?,
+        ("json_array", SecretKeyInput::Bytes(bytes)) => bytes,
+        ("base58" | "base64", SecretKeyInput::Bytes(_)) => {
+            return Err(error_response(
+                StatusCode::BAD_REQUEST,
+                format!("bytes must be a string for the {} format", payload.format),
+            ))
+        }
+        ("json_array", SecretKeyInput::Encoded(_)) => {
+            return Err(error_response(
+                StatusCode::BAD_REQUEST,
+                "bytes must be an array of numbers for the json_array format",
+            ))
+        }
+        (other, _) => {
+            return Err(error_response(
+                StatusCode::BAD_REQUEST,
+                format!("Invalid format '{}'; expected base58, base64 or json_array", other),
+            ))
+        }
+    };
+
+    if secret_bytes.len() != 64 {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Secret key must be 64 bytes, got {}", secret_bytes.len()),
+        ));
+    }
+    let keypair = Keypair::from_bytes(&secret_bytes)
+        .map_err(|_| error_response(StatusCode::BAD_REQUEST, "Invalid keypair format"))?;
+    let secret = keypair.to_bytes();
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: RecoveredKeypair {
+            pubkey: keypair.pubkey().to_string(),
+            secret_base58: bs58::encode(secret).into_string(),
+            secret_base64: general_purpose::STANDARD.encode(secret),
+            secret_json_array: secret.to_vec(),
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct MintToNewAtaRequest {
+    mint: String,
+    mint_authority: String,
+    recipient_owner: String,
+    payer: String,
+    amount: u64,
+    decimals: u8,
+}
+
+#[derive(Serialize, ToSchema)]
+struct MintToNewAtaResponse {
+    ata_address: String,
+    instructions: Vec<InstructionData>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/mint-to-new-ata",
+    tag = "token",
+    request_body = MintToNewAtaRequest,
+    responses(
+        (status = 200, body = SuccessResponse<MintToNewAtaResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn mint_to_new_ata(
+    Json(payload): Json<MintToNewAtaRequest>,
+) -> Result<Json<SuccessResponse<MintToNewAtaResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let mint = parse_pubkey(&payload.mint, "mint")?;
+    let mint_authority = parse_pubkey(&payload.mint_authority, "mint_authority")?;
+    let recipient_owner = parse_pubkey(&payload.recipient_owner, "recipient_owner")?;
+    let payer = parse_pubkey(&payload.payer, "payer")?;
+
+    if payload.amount == 0 {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "amount must be greater than 0",
+        ));
+    }
+
+    let ata_address =
+        get_associated_token_address_with_program_id(&recipient_owner, &mint, &spl_token::ID);
+    // The idempotent create is a no-op when the ATA already exists, so both instructions are always sent
+    let create_ix = ata_instruction::create_associated_token_account_idempotent(
+        &payer,
+        &recipient_owner,
+        &mint,
+        &spl_token::ID,
+    );
+    let mint_ix = token_instruction::mint_to_checked(
+        &spl_token::ID,
+        &mint,
+        &ata_address,
+        &mint_authority,
+        &[],
+        payload.amount,
+        payload.decimals,
+    )
+    .map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to create instruction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: MintToNewAtaResponse {
+            ata_address: ata_address.to_string(),
+            instructions: vec![instruction_data(create_ix), instruction_data(mint_ix)],
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct Secp256k1SignRequest {
+    message: String,
+    private_key_hex: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct Secp256k1Signature {
+    signature: String,
+    recovery_id: u8,
+    ethereum_address: String,
+}
+
+#[derive(Deserialize, ToSchema)]
+struct Secp256k1VerifyRequest {
+    message: String,
+    signature: String,
+    recovery_id: u8,
+    ethereum_address: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct Secp256k1VerifyResponse {
+    valid: bool,
+}
+
+fn parse_secp256k1_secret(private_key_hex: &str) -> Result<libsecp256k1::SecretKey, (StatusCode, Json<ErrorResponse>)> {
+    let bytes = hex::decode(private_key_hex.trim_start_matches("0x"))
+        .map_err(|_| error_response(StatusCode::BAD_REQUEST, "private_key_hex must be hex encoded"))?;
+    libsecp256k1::SecretKey::parse_slice(&bytes)
+        .map_err(|_| error_response(StatusCode::BAD_REQUEST, "Invalid secp256k1 private key"))
+}
+
+fn keccak256_message(message: &str) -> libsecp256k1::Message {
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&sha3::Keccak256::digest(message.as_bytes()));
+    libsecp256k1::Message::parse(&hash)
+}
+
+fn ethereum_address(pubkey: &libsecp256k1::PublicKey) -> String {
+    format!("0x{}", hex::encode(secp256k1_instruction::construct_eth_pubkey(pubkey)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/secp256k1/sign",
+    tag = "secp256k1",
+    request_body = Secp256k1SignRequest,
+    responses(
+        (status = 200, body = SuccessResponse<Secp256k1Signature>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn secp256k1_sign(
+    Json(payload): Json<Secp256k1SignRequest>,
+) -> Result<Json<SuccessResponse<Secp256k1Signature>>, (StatusCode, Json<ErrorResponse>)> {
+    let secret = parse_secp256k1_secret(&payload.private_key_hex)?;
+    let (signature, recovery_id) = libsecp256k1::sign(&keccak256_message(&payload.message), &secret);
+    let pubkey = libsecp256k1::PublicKey::from_secret_key(&secret);
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: Secp256k1Signature {
+            signature: hex::encode(signature.serialize()),
+            recovery_id: recovery_id.serialize(),
+            ethereum_address: ethereum_address(&pubkey),
+        },
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/secp256k1/verify",
+    tag = "secp256k1",
+    request_body = Secp256k1VerifyRequest,
+    responses(
+        (status = 200, body = SuccessResponse<Secp256k1VerifyResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn secp256k1_verify(
+    Json(payload): Json<Secp256k1VerifyRequest>,
+) -> Result<Json<SuccessResponse<Secp256k1VerifyResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let signature_bytes = hex::decode(payload.signature.trim_start_matches("0x"))
+        .map_err(|_| error_response(StatusCode::BAD_REQUEST, "signature must be hex encoded"))?;
+    let signature = libsecp256k1::Signature::parse_standard_slice(&signature_bytes)
+        .map_err(|_| error_response(StatusCode::BAD_REQUEST, "signature must be 64 bytes"))?;
+    let recovery_id = libsecp256k1::RecoveryId::parse(payload.recovery_id)
+        .map_err(|_| error_response(StatusCode::BAD_REQUEST, "recovery_id must be 0-3"))?;
+
+    // A signature that recovers to a different key is simply invalid, not a bad request
+    let valid = libsecp256k1::recover(&keccak256_message(&payload.message), &signature, &recovery_id)
+        .map(|pubkey| ethereum_address(&pubkey).eq_ignore_ascii_case(&payload.ethereum_address))
+        .unwrap_or(false);
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: Secp256k1VerifyResponse { valid },
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/secp256k1/instruction",
+    tag = "secp256k1",
+    request_body = Secp256k1SignRequest,
+    responses(
+        (status = 200, body = SuccessResponse<InstructionData>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn secp256k1_build_instruction(
+    Json(payload): Json<Secp256k1SignRequest>,
+) -> Result<Json<SuccessResponse<InstructionData>>, (StatusCode, Json<ErrorResponse>)> {
+    let secret = parse_secp256k1_secret(&payload.private_key_hex)?;
+    if payload.message.len() > u16::MAX as usize {
+        return Err(error_response(StatusCode::BAD_REQUEST, "message is too long"));
+    }
+    let instruction = secp256k1_instruction::new_secp256k1_instruction(&secret, payload.message.as_bytes());
+
+    Ok(Json(SuccessResponse {
         success: true,
-        data: ix,
-    })
+        data: instruction_data(instruction),
+    }))
 }
 
-#[tokio::main]
-async fn main() {
-    let app = Router::new()
+// Royalties are expressed in basis points, so 10_000 means 100%
+const MAX_SELLER_FEE_BASIS_POINTS: u16 = 10_000;
+
+#[derive(Deserialize, ToSchema)]
+struct CreateMetadataRequest {
+    mint: String,
+    mint_authority: String,
+    payer: String,
+    update_authority: String,
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    is_mutable: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+struct CreateMetadataResponse {
+    metadata_account: String,
+    instruction: InstructionData,
+}
+
+fn check_metadata_field(field: &str, value: &str, max_len: usize) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    if value.len() > max_len {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "{} is {} bytes; the maximum is {} bytes",
+                field,
+                value.len(),
+                max_len
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    post,
+    path = "/nft/create-metadata",
+    tag = "nft",
+    request_body = CreateMetadataRequest,
+    responses(
+        (status = 200, body = SuccessResponse<CreateMetadataResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn create_nft_metadata(
+    Json(payload): Json<CreateMetadataRequest>,
+) -> Result<Json<SuccessResponse<CreateMetadataResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let mint = parse_pubkey(&payload.mint, "mint")?;
+    let mint_authority = parse_pubkey(&payload.mint_authority, "mint_authority")?;
+    let payer = parse_pubkey(&payload.payer, "payer")?;
+    let update_authority = parse_pubkey(&payload.update_authority, "update_authority")?;
+
+    check_metadata_field("name", &payload.name, mpl_token_metadata::MAX_NAME_LENGTH)?;
+    check_metadata_field("symbol", &payload.symbol, mpl_token_metadata::MAX_SYMBOL_LENGTH)?;
+    check_metadata_field("uri", &payload.uri, mpl_token_metadata::MAX_URI_LENGTH)?;
+    if payload.seller_fee_basis_points > MAX_SELLER_FEE_BASIS_POINTS {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!(
+                "seller_fee_basis_points must be at most {}",
+                MAX_SELLER_FEE_BASIS_POINTS
+            ),
+        ));
+    }
+
+    let (metadata_account, _) = Metadata::find_pda(&mint);
+    // The update authority only needs to sign when it is not already signing as payer or mint authority
+    let update_authority_signs = update_authority == payer || update_authority == mint_authority;
+    let instruction = CreateMetadataAccountV3 {
+        metadata: metadata_account,
+        mint,
+        mint_authority,
+        payer,
+        update_authority: (update_authority, update_authority_signs),
+        system_program: solana_sdk::system_program::ID,
+        rent: None,
+    }
+    .instruction(CreateMetadataAccountV3InstructionArgs {
+        data: DataV2 {
+            name: payload.name,
+            symbol: payload.symbol,
+            uri: payload.uri,
+            seller_fee_basis_points: payload.seller_fee_basis_points,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        is_mutable: payload.is_mutable,
+        collection_details: None,
+    });
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: CreateMetadataResponse {
+            metadata_account: metadata_account.to_string(),
+            instruction: instruction_data(instruction),
+        },
+    }))
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct ValidateAddressQuery {
+    mint: Option<String>,
+    program_id: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct ValidateAddressResponse {
+    valid: bool,
+    on_curve: bool,
+    address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    associated_token_account: Option<String>,
+}
+
+// An unparseable address is a normal answer here (valid: false), not a 400.
+#[utoipa::path(
+    get,
+    path = "/address/validate/{address}",
+    tag = "address",
+    params(
+        ("address" = String, Path),
+        ValidateAddressQuery,
+    ),
+    responses(
+        (status = 200, body = SuccessResponse<ValidateAddressResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn validate_address(
+    Path(address): Path<String>,
+    Query(query): Query<ValidateAddressQuery>,
+) -> Result<Json<SuccessResponse<ValidateAddressResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let program_id = parse_token_program(query.program_id.as_deref())?;
+    let mint = match (query.mint.as_deref(), query.program_id.is_some()) {
+        (Some(mint), _) => Some(parse_pubkey(mint, "mint")?),
+        (None, false) => None,
+        (None, true) => {
+            return Err(error_response(
+                StatusCode::BAD_REQUEST,
+                "mint is required to derive the associated token account",
+            ))
+        }
+    };
+
+    let pubkey = Pubkey::from_str(&address).ok();
+    // PDAs can own token accounts too, so the ATA is derived even for off-curve owners
+    let associated_token_account = pubkey.zip(mint).map(|(owner, mint)| {
+        get_associated_token_address_with_program_id(&owner, &mint, &program_id).to_string()
+    });
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: ValidateAddressResponse {
+            valid: pubkey.is_some(),
+            on_curve: pubkey.is_some_and(|pubkey| pubkey.is_on_curve()),
+            address,
+            associated_token_account,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct PartialSignRequest {
+    transaction: String,
+    secret: String,
+}
+
+#[derive(Serialize, ToSchema)]
+struct PartiallySignedTransaction {
+    transaction: String,
+    missing_signers: Vec<String>,
+}
+
+#[utoipa::path(
+    post,
+    path = "/transaction/partial-sign",
+    tag = "transaction",
+    request_body = PartialSignRequest,
+    responses(
+        (status = 200, body = SuccessResponse<PartiallySignedTransaction>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal error", body = ErrorResponse),
+    )
+)]
+async fn partial_sign_transaction(
+    Json(payload): Json<PartialSignRequest>,
+) -> Result<Json<SuccessResponse<PartiallySignedTransaction>>, (StatusCode, Json<ErrorResponse>)> {
+    let mut transaction = decode_transaction(&payload.transaction)?;
+    let keypair = parse_keypair(&payload.secret)?;
+    transaction
+        .sanitize()
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, format!("Malformed transaction: {}", e)))?;
+
+    let required_signers =
+        &transaction.message.account_keys[..transaction.message.header.num_required_signatures as usize];
+    if !required_signers.contains(&keypair.pubkey()) {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            format!("{} is not a required signer of this transaction", keypair.pubkey()),
+        ));
+    }
+
+    // Signs with the blockhash already in the message; other parties' signature slots are left untouched
+    let recent_blockhash = transaction.message.recent_blockhash;
+    transaction
+        .try_partial_sign(&[&keypair], recent_blockhash)
+        .map_err(|e| error_response(StatusCode::BAD_REQUEST, format!("Failed to sign transaction: {}", e)))?;
+
+    let missing_signers = transaction
+        .message
+        .account_keys
+        .iter()
+        .zip(&transaction.signatures)
+        .filter(|(_, signature)| **signature == TransactionSignature::default())
+        .map(|(pubkey, _)| pubkey.to_string())
+        .collect();
+    let wire_bytes = bincode::serialize(&transaction).map_err(|e| {
+        error_response(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to serialize transaction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: PartiallySignedTransaction {
+            transaction: general_purpose::STANDARD.encode(wire_bytes),
+            missing_signers,
+        },
+    }))
+}
+
+#[derive(Deserialize, ToSchema)]
+struct SetAuthorityRequest {
+    account: String,
+    current_authority: String,
+    /// One of `mint_tokens`, `freeze_account`, `account_owner` or `close_account`
+    authority_type: String,
+    /// Omit or send `null` to revoke the authority permanently; this cannot be undone
+    new_authority: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+struct SetAuthorityResponse {
+    #[serde(flatten)]
+    instruction: InstructionData,
+    /// Present when the instruction revokes the authority, which is irreversible once executed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    warning: Option<String>,
+}
+
+fn parse_authority_type(value: &str) -> Result<token_instruction::AuthorityType, (StatusCode, Json<ErrorResponse>)> {
+    match value {
+        "mint_tokens" => Ok(token_instruction::AuthorityType::MintTokens),
+        "freeze_account" => Ok(token_instruction::AuthorityType::FreezeAccount),
+        "account_owner" => Ok(token_instruction::AuthorityType::AccountOwner),
+        "close_account" => Ok(token_instruction::AuthorityType::CloseAccount),
+        _ => Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "authority_type must be one of mint_tokens, freeze_account, account_owner, close_account",
+        )),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/token/set-authority",
+    tag = "token",
+    request_body = SetAuthorityRequest,
+    responses(
+        (status = 200, body = SuccessResponse<SetAuthorityResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+    )
+)]
+async fn set_token_authority(
+    Json(payload): Json<SetAuthorityRequest>,
+) -> Result<Json<SuccessResponse<SetAuthorityResponse>>, (StatusCode, Json<ErrorResponse>)> {
+    let account = parse_pubkey(&payload.account, "account")?;
+    let current_authority = parse_pubkey(&payload.current_authority, "current_authority")?;
+    let authority_type = parse_authority_type(&payload.authority_type)?;
+    let new_authority = payload
+        .new_authority
+        .as_deref()
+        .map(|value| parse_pubkey(value, "new_authority"))
+        .transpose()?;
+
+    // Every token account must have an owner, so only the other authorities can be revoked
+    if new_authority.is_none() && authority_type == token_instruction::AuthorityType::AccountOwner {
+        return Err(error_response(
+            StatusCode::BAD_REQUEST,
+            "new_authority is required for account_owner; a token account owner cannot be revoked",
+        ));
+    }
+
+    let ix = token_instruction::set_authority(
+        &spl_token::ID,
+        &account,
+        new_authority.as_ref(),
+        authority_type,
+        &current_authority,
+        &[],
+    )
+    .map_err(|e| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Failed to create instruction: {}", e),
+        )
+    })?;
+
+    Ok(Json(SuccessResponse {
+        success: true,
+        data: SetAuthorityResponse {
+            instruction: instruction_data(ix),
+            warning: new_authority.is_none().then(|| {
+                format!(
+                    "This permanently revokes the {} authority; it cannot be set again once the transaction executes",
+                    payload.authority_type
+                )
+            }),
+        },
+    }))
+}
+
+// Every routed handler is listed here; request and response schemas are collected from them.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Solana HTTP Server",
+        description = "Builds and signs Solana instructions and transactions, and proxies common RPC queries.",
+    ),
+    paths(
+        root,
+        generate_keypair,
+        sign_message,
+        verify_message,
+        create_token,
+        mint_token,
+        send_sol,
+        send_token,
+        initialize_account3,
+        transfer_many,
+        token_transfer_many,
+        get_account_data_size,
+        well_known_address,
+        get_slot_leaders_schedule,
+        get_block,
+        get_inflation_governor,
+        get_inflation_rate,
+        get_accounts_by_program_id_with_size,
+        get_memo_transfer_required,
+        check_transfer_authority,
+        get_confirmed_blocks_with_limit,
+        get_signatures_for_address_until_not_found,
+        derive_stake_account,
+        get_mint_close_authority,
+        get_total_supply,
+        get_freeze_authority,
+        search_transaction_history,
+        create_and_delegate_stake,
+        get_recent_blockhash_fees,
+        close_mint,
+        get_program_accounts_for_mint,
+        get_block_commitment,
+        get_supply_and_largest_holders,
+        verify_program_account_ownership,
+        get_stakes_in_current_epoch,
+        get_nonce_account,
+        find_all_pdas_for_seeds,
+        build_transaction,
+        send_transaction,
+        get_blockhash,
+        create_token_account,
+        burn_token,
+        close_token_account,
+        freeze_token_account,
+        thaw_token_account,
+        approve_delegate,
+        revoke_delegate,
+        derive_pda,
+        simulate_transaction,
+        get_account_info,
+        get_token_balance,
+        request_airdrop,
+        batch_transaction,
+        transfer_checked,
+        build_memo,
+        keypair_from_mnemonic,
+        vanity_keypair,
+        create_account,
+        allocate_account,
+        assign_account,
+        transfer_with_seed,
+        create_token_2022,
+        decode_transaction_details,
+        build_v0_transaction,
+        create_stake,
+        delegate_stake,
+        sign_message_batch,
+        verify_message_batch,
+        get_token_info,
+        get_token_accounts_by_owner,
+        get_transaction_fee,
+        recover_keypair,
+        mint_to_new_ata,
+        secp256k1_sign,
+        secp256k1_verify,
+        secp256k1_build_instruction,
+        create_nft_metadata,
+        validate_address,
+        partial_sign_transaction,
+        set_token_authority,
+        health,
+        ready,
+        render_metrics,
+        openapi_json,
+    ),
+    modifiers(&RateLimitResponses),
+)]
+struct ApiDoc;
+
+// Routes registered after the rate limit layer in `app`, which never answer 429.
+const UNLIMITED_PATHS: [&str; 4] = ["/health", "/ready", "/metrics", "/openapi.json"];
+
+// The rate limiter rejects requests before they reach a handler, so its 429 is added to every limited
+// operation here rather than in each `#[utoipa::path]`.
+struct RateLimitResponses;
+
+impl Modify for RateLimitResponses {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let response = ResponseBuilder::new()
+            .description("Rate limit exceeded")
+            .header(
+                RETRY_AFTER.as_str(),
+                HeaderBuilder::new()
+                    .schema(ObjectBuilder::new().schema_type(Type::Integer))
+                    .description(Some("Seconds until the next request is allowed"))
+                    .build(),
+            )
+            .content(
+                "application/json",
+                ContentBuilder::new()
+                    .schema(Some(Ref::from_schema_name("ErrorResponse")))
+                    .build(),
+            )
+            .build();
+
+        for (path, item) in openapi.paths.paths.iter_mut() {
+            if UNLIMITED_PATHS.contains(&path.as_str()) {
+                continue;
+            }
+            for operation in [&mut item.get, &mut item.post].into_iter().flatten() {
+                operation
+                    .responses
+                    .responses
+                    .insert("429".to_string(), response.clone().into());
+            }
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/openapi.json",
+    tag = "server",
+    responses(
+        (status = 200, description = "OpenAPI 3 document for this server", body = Object),
+    )
+)]
+async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+fn app(state: AppState) -> Router {
+    Router::new()
         .route("/", get(root))
         .route("/keypair", post(generate_keypair))
         .route("/message/sign", post(sign_message))
@@ -399,10 +6158,958 @@ async fn main() {
         .route("/token/create", post(create_token))
         .route("/token/mint", post(mint_token))
         .route("/send/sol", post(send_sol))
-        .route("/send/token", post(send_token));
+        // transfer does not check the mint or decimals; point callers at transfer_checked instead
+        .route(
+            "/send/token",
+            post(send_token).layer(SetResponseHeaderLayer::overriding(
+                HeaderName::from_static("deprecation-notice"),
+                HeaderValue::from_static(
+                    "/send/token is deprecated; use POST /token/transfer-checked instead",
+                ),
+            )),
+        )
+        .route("/token/initialize-account3", post(initialize_account3))
+        .route("/system/transfer-many", post(transfer_many))
+        .route("/token/transfer-many", post(token_transfer_many))
+        .route("/rpc/get-account-data-size", post(get_account_data_size))
+        .route("/address/well-known", post(well_known_address))
+        .route("/rpc/get-slot-leaders-schedule", post(get_slot_leaders_schedule))
+        .route("/rpc/get-block", post(get_block))
+        .route("/rpc/get-inflation-governor", post(get_inflation_governor))
+        .route("/rpc/inflation-rate", get(get_inflation_rate))
+        .route(
+            "/rpc/get-accounts-by-program-id-with-size",
+            post(get_accounts_by_program_id_with_size),
+        )
+        .route("/token/get-memo-transfer-required", post(get_memo_transfer_required))
+        .route("/token/check-transfer-authority", post(check_transfer_authority))
+        .route(
+            "/rpc/get-confirmed-blocks-with-limit",
+            post(get_confirmed_blocks_with_limit),
+        )
+        .route(
+            "/rpc/get-signatures-for-address-until-not-found",
+            post(get_signatures_for_address_until_not_found),
+        )
+        .route("/address/derive-stake-account", post(derive_stake_account))
+        .route("/token/get-mint-close-authority", post(get_mint_close_authority))
+        .route("/rpc/get-total-supply", post(get_total_supply))
+        .route("/token/get-freeze-authority", post(get_freeze_authority))
+        .route("/rpc/search-transaction-history", post(search_transaction_history))
+        .route("/token/create-and-delegate-stake", post(create_and_delegate_stake))
+        .route("/rpc/get-recent-blockhash-fees", post(get_recent_blockhash_fees))
+        .route("/token/close-authority/close-mint", post(close_mint))
+        .route(
+            "/token/get-program-accounts-for-mint",
+            post(get_program_accounts_for_mint),
+        )
+        .route("/rpc/get-block-commitment", post(get_block_commitment))
+        .route(
+            "/token/get-supply-and-largest-holders",
+            post(get_supply_and_largest_holders),
+        )
+        .route(
+            "/address/verify-program-account-ownership",
+            post(verify_program_account_ownership),
+        )
+        .route("/rpc/get-stakes-in-current-epoch", post(get_stakes_in_current_epoch))
+        .route("/rpc/get-nonce-account", post(get_nonce_account))
+        .route("/address/find-all-pdas-for-seeds", post(find_all_pdas_for_seeds))
+        .route("/transaction/build", post(build_transaction))
+        .route("/transaction/send", post(send_transaction))
+        .route("/blockhash", get(get_blockhash))
+        .route("/token/account/create", post(create_token_account))
+        .route("/token/burn", post(burn_token))
+        .route("/token/close", post(close_token_account))
+        .route("/token/freeze", post(freeze_token_account))
+        .route("/token/thaw", post(thaw_token_account))
+        .route("/token/approve", post(approve_delegate))
+        .route("/token/revoke", post(revoke_delegate))
+        .route("/pda/derive", post(derive_pda))
+        .route("/transaction/simulate", post(simulate_transaction))
+        .route("/account/:pubkey", get(get_account_info))
+        .route("/token/balance/:account", get(get_token_balance))
+        .route("/airdrop", post(request_airdrop))
+        .route("/transaction/batch", post(batch_transaction))
+        .route("/token/transfer-checked", post(transfer_checked))
+        .route("/memo", post(build_memo))
+        .route("/keypair/from-mnemonic", post(keypair_from_mnemonic))
+        .route("/keypair/vanity", post(vanity_keypair))
+        .route("/system/create-account", post(create_account))
+        .route("/system/allocate", post(allocate_account))
+        .route("/system/assign", post(assign_account))
+        .route("/system/transfer-with-seed", post(transfer_with_seed))
+        .route("/token/create-2022", post(create_token_2022))
+        .route("/transaction/decode", post(decode_transaction_details))
+        .route("/transaction/build-v0", post(build_v0_transaction))
+        .route("/stake/create", post(create_stake))
+        .route("/stake/delegate", post(delegate_stake))
+        .route("/message/sign-batch", post(sign_message_batch))
+        .route("/message/verify-batch", post(verify_message_batch))
+        .route("/token/info/:mint", get(get_token_info))
+        .route("/token/accounts/:owner", get(get_token_accounts_by_owner))
+        .route("/transaction/fee", post(get_transaction_fee))
+        .route("/keypair/recover", post(recover_keypair))
+        .route("/token/mint-to-new-ata", post(mint_to_new_ata))
+        .route("/secp256k1/sign", post(secp256k1_sign))
+        .route("/secp256k1/verify", post(secp256k1_verify))
+        .route("/secp256k1/instruction", post(secp256k1_build_instruction))
+        .route("/nft/create-metadata", post(create_nft_metadata))
+        .route("/address/validate/:address", get(validate_address))
+        .route("/transaction/partial-sign", post(partial_sign_transaction))
+        .route("/token/set-authority", post(set_token_authority))
+        .layer(middleware::from_fn_with_state(state.clone(), rate_limit))
+        // Registered after the rate limiter so probes are never throttled
+        .route("/health", get(health))
+        .route("/ready", get(ready))
+        .route("/metrics", get(render_metrics))
+        .route("/openapi.json", get(openapi_json))
+        .layer(middleware::from_fn(track_metrics))
+        // Negotiated from Accept-Encoding; responses are sent uncompressed when neither gzip nor zstd is accepted
+        .layer(CompressionLayer::new())
+        .layer(state.config.cors_layer())
+        .layer(middleware::from_fn_with_state(state.clone(), reject_unlisted_preflight))
+        // Layers wrap inside-out: the ID is assigned first, then traced, then echoed back
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(|request: &Request| {
+                    let request_id = request
+                        .headers()
+                        .get("x-request-id")
+                        .and_then(|value| value.to_str().ok())
+                        .unwrap_or_default();
+                    tracing::info_span!(
+                        "request",
+                        method = %request.method(),
+                        path = %request.uri().path(),
+                        request_id = %request_id,
+                    )
+                })
+                .on_response(
+                    DefaultOnResponse::new()
+                        .level(Level::INFO)
+                        .latency_unit(LatencyUnit::Millis),
+                ),
+        )
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+        .with_state(state)
+}
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt()
+        .json()
+        .with_current_span(true)
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
+    let config = Arc::new(envy::from_env::<Config>().expect("Invalid server configuration"));
+    let metrics = PrometheusBuilder::new()
+        .set_quantiles(&[0.5, 0.95, 0.99])
+        .and_then(PrometheusBuilder::install_recorder)
+        .expect("Failed to install Prometheus recorder");
+    let address = format!("{}:{}", config.server_host, config.server_port);
+    let state = AppState {
+        rpc_pool: Arc::new(RpcPool::new(config.rpc_urls())),
+        rate_limiter: Arc::new(RateLimiter::new(config.rate_limit_rpm, config.rate_limit_burst)),
+        config: config.clone(),
+        ready: Arc::new(AtomicBool::new(false)),
+        metrics,
+    };
+    let ready_flag = state.ready.clone();
+    let app = app(state).into_make_service_with_connect_info::<SocketAddr>();
+
+    let Some((cert_path, key_path)) = config.tls_paths() else {
+        let listener = TcpListener::bind(&address).await.unwrap();
+        ready_flag.store(true, Ordering::Release);
+        tracing::info!("🚀 Server running at http://{}", address);
+
+        serve(listener, app).await.unwrap();
+        return;
+    };
+
+    let tls_config = RustlsConfig::from_pem_file(cert_path, key_path)
+        .await
+        .expect("Failed to load TLS certificate or key");
+    if config.redirect_http_to_https {
+        let redirect_address = format!("{}:{}", config.server_host, config.http_redirect_port);
+        let redirect_app = Router::new()
+            .fallback(redirect_to_https)
+            .with_state(config.server_port);
+        let redirect_listener = TcpListener::bind(&redirect_address).await.unwrap();
+        tracing::info!("Redirecting http://{} to HTTPS", redirect_address);
+        tokio::spawn(async move { serve(redirect_listener, redirect_app).await.unwrap() });
+    }
+
+    let listener = std::net::TcpListener::bind(&address).unwrap();
+    listener.set_nonblocking(true).unwrap();
+    ready_flag.store(true, Ordering::Release);
+    tracing::info!("🚀 Server running at https://{}", address);
+
+    axum_server::from_tcp_rustls(listener, tls_config)
+        .serve(app)
+        .await
+        .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::extract::connect_info::MockConnectInfo;
+    use axum::http::header::{ACCEPT_ENCODING, CONTENT_ENCODING, CONTENT_TYPE};
+    use http_body_util::BodyExt;
+    use std::collections::BTreeSet;
+    use std::io::Read;
+    use tower::ServiceExt;
+
+    // No RPC node is listening; these tests only exercise routes that never reach it.
+    fn test_app() -> Router {
+        let config: Config = envy::from_iter(std::iter::empty::<(String, String)>()).unwrap();
+        let state = AppState {
+            rpc_pool: Arc::new(RpcPool::new(vec!["http://127.0.0.1:1".to_string()])),
+            rate_limiter: Arc::new(RateLimiter::new(0, 0)),
+            config: Arc::new(config),
+            ready: Arc::new(AtomicBool::new(true)),
+            metrics: PrometheusBuilder::new().build_recorder().handle(),
+        };
+        app(state).layer(MockConnectInfo(SocketAddr::from(([127, 0, 0, 1], 0))))
+    }
+
+    fn generate_keypair_request(accept_encoding: Option<&str>) -> Request {
+        let mut request = axum::http::Request::post("/keypair");
+        if let Some(encoding) = accept_encoding {
+            request = request.header(ACCEPT_ENCODING, encoding);
+        }
+        request.body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn gzips_response_when_accepted() {
+        let response = test_app()
+            .oneshot(generate_keypair_request(Some("gzip")))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CONTENT_ENCODING], "gzip");
+
+        let compressed = response.into_body().collect().await.unwrap().to_bytes();
+        let mut json = String::new();
+        flate2::read::GzDecoder::new(&compressed[..])
+            .read_to_string(&mut json)
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(body["success"], true);
+        assert!(body["data"]["pubkey"].is_string());
+    }
+
+    #[tokio::test]
+    async fn skips_compression_without_accept_encoding() {
+        let response = test_app()
+            .oneshot(generate_keypair_request(None))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().get(CONTENT_ENCODING).is_none());
+
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["success"], true);
+    }
+
+    async fn post_json(uri: &str, body: serde_json::Value) -> (StatusCode, HeaderMap, serde_json::Value) {
+        let request = axum::http::Request::post(uri)
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = test_app().oneshot(request).await.unwrap();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, headers, serde_json::from_slice(&body).unwrap())
+    }
+
+    fn assert_error(status: StatusCode, body: &serde_json::Value, expected: StatusCode) {
+        assert_eq!(status, expected);
+        assert_eq!(body["success"], false);
+        assert!(body["error"].is_string());
+    }
+
+    fn new_pubkey() -> String {
+        Keypair::new().pubkey().to_string()
+    }
+
+    #[tokio::test]
+    async fn root_reports_running() {
+        let response = test_app()
+            .oneshot(axum::http::Request::get("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert!(std::str::from_utf8(&body).unwrap().contains("Solana Rust Server is running"));
+    }
+
+    #[tokio::test]
+    async fn root_rejects_post() {
+        let response = test_app()
+            .oneshot(axum::http::Request::post("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn keypair_returns_matching_pubkey_and_secret() {
+        let (status, _, body) = post_json("/keypair", json!({})).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["success"], true);
+        let secret = bs58::decode(body["data"]["secret"].as_str().unwrap()).into_vec().unwrap();
+        let keypair = Keypair::from_bytes(&secret).unwrap();
+        assert_eq!(body["data"]["pubkey"], keypair.pubkey().to_string());
+    }
+
+    #[tokio::test]
+    async fn keypair_rejects_get() {
+        let response = test_app()
+            .oneshot(axum::http::Request::get("/keypair").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    async fn sign_message_returns_signature() {
+        let keypair = Keypair::new();
+        let (status, _, body) = post_json(
+            "/message/sign",
+            json!({ "message": "hello", "secret": keypair.to_base58_string() }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["success"], true);
+        assert_eq!(body["data"]["public_key"], keypair.pubkey().to_string());
+        assert_eq!(body["data"]["message"], "hello");
+        let signature = general_purpose::STANDARD
+            .decode(body["data"]["signature"].as_str().unwrap())
+            .unwrap();
+        assert_eq!(signature.len(), 64);
+    }
+
+    #[tokio::test]
+    async fn sign_message_rejects_invalid_secret() {
+        let (status, _, body) =
+            post_json("/message/sign", json!({ "message": "hello", "secret": "not-a-key" })).await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn verify_message_rejects_tampered_message() {
+        let keypair = Keypair::new();
+        let signature = keypair.sign_message(b"hello");
+        let (status, _, body) = post_json(
+            "/message/verify",
+            json!({
+                "message": "goodbye",
+                "signature": general_purpose::STANDARD.encode(signature.as_ref()),
+                "pubkey": keypair.pubkey().to_string(),
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["success"], true);
+        assert_eq!(body["data"]["valid"], false);
+        assert_eq!(body["data"]["message"], "goodbye");
+    }
+
+    #[tokio::test]
+    async fn verify_message_rejects_invalid_signature_encoding() {
+        let (status, _, body) = post_json(
+            "/message/verify",
+            json!({ "message": "hello", "signature": "%%%", "pubkey": new_pubkey() }),
+        )
+        .await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn signature_from_sign_is_accepted_by_verify() {
+        let keypair = Keypair::new();
+        let (_, _, signed) = post_json(
+            "/message/sign",
+            json!({ "message": "round trip", "secret": keypair.to_base58_string() }),
+        )
+        .await;
+
+        let (status, _, body) = post_json(
+            "/message/verify",
+            json!({
+                "message": "round trip",
+                "signature": signed["data"]["signature"],
+                "pubkey": signed["data"]["public_key"],
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["valid"], true);
+        assert_eq!(body["data"]["pubkey"], keypair.pubkey().to_string());
+    }
+
+    #[tokio::test]
+    async fn create_token_returns_initialize_mint_instruction() {
+        let mint = new_pubkey();
+        let (status, _, body) = post_json(
+            "/token/create",
+            json!({ "mint": mint, "mintAuthority": new_pubkey(), "decimals": 6 }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["success"], true);
+        assert_eq!(body["data"]["program_id"], spl_token::ID.to_string());
+        assert_eq!(body["data"]["accounts"][0]["pubkey"], mint);
+        assert!(body["data"]["instruction_data"].is_string());
+    }
+
+    #[tokio::test]
+    async fn create_token_rejects_invalid_mint() {
+        let (status, _, body) = post_json(
+            "/token/create",
+            json!({ "mint": "bad", "mintAuthority": new_pubkey(), "decimals": 6 }),
+        )
+        .await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn mint_token_returns_mint_to_instruction() {
+        let authority = new_pubkey();
+        let (status, _, body) = post_json(
+            "/token/mint",
+            json!({
+                "mint": new_pubkey(),
+                "destination": new_pubkey(),
+                "authority": authority,
+                "amount": 1_000,
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["success"], true);
+        assert_eq!(body["data"]["program_id"], spl_token::ID.to_string());
+        assert_eq!(body["data"]["accounts"][2]["pubkey"], authority);
+        assert_eq!(body["data"]["accounts"][2]["is_signer"], true);
+    }
+
+    #[tokio::test]
+    async fn mint_token_rejects_invalid_destination() {
+        let (status, _, body) = post_json(
+            "/token/mint",
+            json!({
+                "mint": new_pubkey(),
+                "destination": "bad",
+                "authority": new_pubkey(),
+                "amount": 1_000,
+            }),
+        )
+        .await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn send_sol_returns_transfer_instruction() {
+        let (from, to) = (new_pubkey(), new_pubkey());
+        let (status, _, body) =
+            post_json("/send/sol", json!({ "from": from, "to": to, "lamports": 5_000 })).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["success"], true);
+        assert_eq!(body["data"]["program_id"], solana_sdk::system_program::ID.to_string());
+        assert_eq!(body["data"]["accounts"][0]["pubkey"], from);
+        assert_eq!(body["data"]["accounts"][1]["pubkey"], to);
+    }
+
+    #[tokio::test]
+    async fn send_sol_rejects_zero_lamports() {
+        let (status, _, body) = post_json(
+            "/send/sol",
+            json!({ "from": new_pubkey(), "to": new_pubkey(), "lamports": 0 }),
+        )
+        .await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn send_token_returns_transfer_instruction_with_deprecation_notice() {
+        let (status, headers, body) = post_json(
+            "/send/token",
+            json!({
+                "destination": new_pubkey(),
+                "mint": new_pubkey(),
+                "owner": new_pubkey(),
+                "amount": 10,
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["success"], true);
+        assert!(body["data"]["accounts"].is_array());
+        assert!(body["data"]["data"].is_array());
+        assert!(headers.contains_key("deprecation-notice"));
+    }
+
+    #[tokio::test]
+    async fn send_token_rejects_zero_amount() {
+        let (status, _, body) = post_json(
+            "/send/token",
+            json!({
+                "destination": new_pubkey(),
+                "mint": new_pubkey(),
+                "owner": new_pubkey(),
+                "amount": 0,
+            }),
+        )
+        .await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    // Every path registered in `app`, in the notation OpenAPI uses for path parameters.
+    const ROUTED_PATHS: &[&str] = &[
+        "/",
+        "/keypair",
+        "/message/sign",
+        "/message/verify",
+        "/token/create",
+        "/token/mint",
+        "/send/sol",
+        "/send/token",
+        "/token/initialize-account3",
+        "/system/transfer-many",
+        "/token/transfer-many",
+        "/rpc/get-account-data-size",
+        "/address/well-known",
+        "/rpc/get-slot-leaders-schedule",
+        "/rpc/get-block",
+        "/rpc/get-inflation-governor",
+        "/rpc/inflation-rate",
+        "/rpc/get-accounts-by-program-id-with-size",
+        "/token/get-memo-transfer-required",
+        "/token/check-transfer-authority",
+        "/rpc/get-confirmed-blocks-with-limit",
+        "/rpc/get-signatures-for-address-until-not-found",
+        "/address/derive-stake-account",
+        "/token/get-mint-close-authority",
+        "/rpc/get-total-supply",
+        "/token/get-freeze-authority",
+        "/rpc/search-transaction-history",
+        "/token/create-and-delegate-stake",
+        "/rpc/get-recent-blockhash-fees",
+        "/token/close-authority/close-mint",
+        "/token/get-program-accounts-for-mint",
+        "/rpc/get-block-commitment",
+        "/token/get-supply-and-largest-holders",
+        "/address/verify-program-account-ownership",
+        "/rpc/get-stakes-in-current-epoch",
+        "/rpc/get-nonce-account",
+        "/address/find-all-pdas-for-seeds",
+        "/transaction/build",
+        "/transaction/send",
+        "/blockhash",
+        "/token/account/create",
+        "/token/burn",
+        "/token/close",
+        "/token/freeze",
+        "/token/thaw",
+        "/token/approve",
+        "/token/revoke",
+        "/pda/derive",
+        "/transaction/simulate",
+        "/account/{pubkey}",
+        "/token/balance/{account}",
+        "/airdrop",
+        "/transaction/batch",
+        "/token/transfer-checked",
+        "/memo",
+        "/keypair/from-mnemonic",
+        "/keypair/vanity",
+        "/system/create-account",
+        "/system/allocate",
+        "/system/assign",
+        "/system/transfer-with-seed",
+        "/token/create-2022",
+        "/transaction/decode",
+        "/transaction/build-v0",
+        "/stake/create",
+        "/stake/delegate",
+        "/message/sign-batch",
+        "/message/verify-batch",
+        "/token/info/{mint}",
+        "/token/accounts/{owner}",
+        "/transaction/fee",
+        "/keypair/recover",
+        "/token/mint-to-new-ata",
+        "/secp256k1/sign",
+        "/secp256k1/verify",
+        "/secp256k1/instruction",
+        "/nft/create-metadata",
+        "/address/validate/{address}",
+        "/transaction/partial-sign",
+        "/token/set-authority",
+        "/health",
+        "/ready",
+        "/metrics",
+        "/openapi.json",
+    ];
+
+    #[tokio::test]
+    async fn openapi_documents_every_route() {
+        let response = test_app()
+            .oneshot(axum::http::Request::get("/openapi.json").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let doc: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let paths = doc["paths"].as_object().unwrap();
+
+        let documented: BTreeSet<&str> = paths.keys().map(String::as_str).collect();
+        let routed: BTreeSet<&str> = ROUTED_PATHS.iter().copied().collect();
+        assert_eq!(documented, routed);
+        assert!(paths["/keypair"]["post"]["responses"].get("429").is_some());
+        assert!(paths["/health"]["get"]["responses"].get("429").is_none());
+    }
+
+    #[tokio::test]
+    async fn set_authority_warns_when_revoking() {
+        let (status, _, body) = post_json(
+            "/token/set-authority",
+            json!({
+                "account": new_pubkey(),
+                "current_authority": new_pubkey(),
+                "authority_type": "mint_tokens",
+                "new_authority": null,
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["program_id"], spl_token::ID.to_string());
+        assert!(body["data"]["warning"].is_string());
+    }
+
+    #[tokio::test]
+    async fn set_authority_omits_warning_when_transferring() {
+        let (status, _, body) = post_json(
+            "/token/set-authority",
+            json!({
+                "account": new_pubkey(),
+                "current_authority": new_pubkey(),
+                "authority_type": "freeze_account",
+                "new_authority": new_pubkey(),
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["data"].get("warning").is_none());
+    }
+
+    #[tokio::test]
+    async fn set_authority_rejects_revoking_account_owner() {
+        let (status, _, body) = post_json(
+            "/token/set-authority",
+            json!({
+                "account": new_pubkey(),
+                "current_authority": new_pubkey(),
+                "authority_type": "account_owner",
+            }),
+        )
+        .await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn set_authority_rejects_unknown_authority_type() {
+        let (status, _, body) = post_json(
+            "/token/set-authority",
+            json!({
+                "account": new_pubkey(),
+                "current_authority": new_pubkey(),
+                "authority_type": "owner",
+                "new_authority": new_pubkey(),
+            }),
+        )
+        .await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn transfer_with_seed_accepts_derived_from() {
+        let (base, owner) = (Keypair::new().pubkey(), Keypair::new().pubkey());
+        let from = Pubkey::create_with_seed(&base, "savings", &owner).unwrap();
+        let (status, _, body) = post_json(
+            "/system/transfer-with-seed",
+            json!({
+                "from": from.to_string(),
+                "from_base": base.to_string(),
+                "from_seed": "savings",
+                "from_owner": owner.to_string(),
+                "to": new_pubkey(),
+                "lamports": 1_000,
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["accounts"][0]["pubkey"], from.to_string());
+        assert_eq!(body["data"]["accounts"][1]["pubkey"], base.to_string());
+        assert_eq!(body["data"]["accounts"][1]["is_signer"], true);
+    }
+
+    #[tokio::test]
+    async fn transfer_with_seed_rejects_mismatched_from() {
+        let (status, _, body) = post_json(
+            "/system/transfer-with-seed",
+            json!({
+                "from": new_pubkey(),
+                "from_base": new_pubkey(),
+                "from_seed": "savings",
+                "from_owner": new_pubkey(),
+                "to": new_pubkey(),
+                "lamports": 1_000,
+            }),
+        )
+        .await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn recover_keypair_round_trips_base58() {
+        let keypair = Keypair::new();
+        let secret = bs58::encode(keypair.to_bytes()).into_string();
+        let (status, _, body) =
+            post_json("/keypair/recover", json!({ "bytes": secret, "format": "base58" })).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["pubkey"], keypair.pubkey().to_string());
+        assert_eq!(body["data"]["secret_base58"], secret);
+        assert_eq!(body["data"]["secret_json_array"], json!(keypair.to_bytes().to_vec()));
+    }
+
+    #[tokio::test]
+    async fn recover_keypair_rejects_short_key() {
+        let (status, _, body) =
+            post_json("/keypair/recover", json!({ "bytes": vec![1u8; 32], "format": "json_array" })).await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn recover_keypair_rejects_mismatched_format() {
+        let (status, _, body) =
+            post_json("/keypair/recover", json!({ "bytes": vec![1u8; 64], "format": "base58" })).await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn vanity_keypair_matches_prefix() {
+        let (status, _, body) =
+            post_json("/keypair/vanity", json!({ "prefix": "a", "timeout_seconds": 5 })).await;
+
+        assert_eq!(status, StatusCode::OK);
+        let pubkey = body["data"]["pubkey"].as_str().unwrap();
+        assert!(pubkey.to_lowercase().starts_with('a'));
+        let secret = bs58::decode(body["data"]["secret"].as_str().unwrap()).into_vec().unwrap();
+        assert_eq!(Keypair::from_bytes(&secret).unwrap().pubkey().to_string(), pubkey);
+    }
+
+    #[tokio::test]
+    async fn vanity_keypair_rejects_non_base58_prefix() {
+        for prefix in ["0", "O", "I"] {
+            let (status, _, body) = post_json(
+                "/keypair/vanity",
+                json!({ "prefix": prefix, "case_sensitive": true }),
+            )
+            .await;
+
+            assert_error(status, &body, StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[tokio::test]
+    async fn vanity_keypair_rejects_timeout_above_max() {
+        let (status, _, body) = post_json(
+            "/keypair/vanity",
+            json!({ "prefix": "a", "timeout_seconds": MAX_VANITY_TIMEOUT_SECS + 1 }),
+        )
+        .await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn derive_pda_returns_canonical_bump() {
+        let program_id = Keypair::new().pubkey();
+        let (address, bump) = Pubkey::find_program_address(&[b"vault"], &program_id);
+        let (status, _, body) = post_json(
+            "/pda/derive",
+            json!({
+                "program_id": program_id.to_string(),
+                "seeds": [general_purpose::STANDARD.encode(b"vault")],
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["address"], address.to_string());
+        assert_eq!(body["data"]["bump"], bump);
+    }
+
+    #[tokio::test]
+    async fn derive_pda_rejects_invalid_seed() {
+        let (status, _, body) = post_json(
+            "/pda/derive",
+            json!({ "program_id": new_pubkey(), "seeds": ["not base64!"] }),
+        )
+        .await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn find_all_pdas_marks_first_bump_canonical() {
+        let program_id = Keypair::new().pubkey();
+        let (address, bump) = Pubkey::find_program_address(&[b"vault"], &program_id);
+        let (status, _, body) = post_json(
+            "/address/find-all-pdas-for-seeds",
+            json!({
+                "program_id": program_id.to_string(),
+                "seeds": [{ "type": "string", "value": "vault" }],
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        let pdas = body["data"].as_array().unwrap();
+        assert_eq!(pdas[0]["address"], address.to_string());
+        assert_eq!(pdas[0]["bump"], bump);
+        assert_eq!(pdas[0]["canonical"], true);
+        assert!(pdas[1..].iter().all(|pda| pda["canonical"] == false));
+    }
+
+    #[tokio::test]
+    async fn find_all_pdas_rejects_too_many_seeds() {
+        let seeds = vec![json!({ "type": "string", "value": "x" }); MAX_SEEDS];
+        let (status, _, body) = post_json(
+            "/address/find-all-pdas-for-seeds",
+            json!({ "program_id": new_pubkey(), "seeds": seeds }),
+        )
+        .await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn transfer_many_reports_invalid_entries() {
+        let (status, _, body) = post_json(
+            "/system/transfer-many",
+            json!({
+                "from": new_pubkey(),
+                "transfers": [
+                    { "to": new_pubkey(), "lamports": 100 },
+                    { "to": "not-a-pubkey", "lamports": 100 },
+                    { "to": new_pubkey(), "lamports": 250 },
+                ],
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["instructions"].as_array().unwrap().len(), 2);
+        assert_eq!(body["data"]["errors"][0]["index"], 1);
+        assert_eq!(body["data"]["total_lamports"], 350);
+    }
+
+    #[tokio::test]
+    async fn transfer_many_rejects_batch_over_limit() {
+        let (status, _, body) = post_json(
+            "/system/transfer-many",
+            json!({
+                "from": new_pubkey(),
+                "transfers": [
+                    { "to": new_pubkey(), "lamports": 100 },
+                    { "to": new_pubkey(), "lamports": 100 },
+                ],
+                "limit": 1,
+            }),
+        )
+        .await;
+
+        assert_error(status, &body, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn token_transfer_many_reports_invalid_entries() {
+        let (status, _, body) = post_json(
+            "/token/transfer-many",
+            json!({
+                "source": new_pubkey(),
+                "mint": new_pubkey(),
+                "owner": new_pubkey(),
+                "decimals": 6,
+                "transfers": [
+                    { "destination": new_pubkey(), "amount": 100 },
+                    { "destination": "not-a-pubkey", "amount": u64::MAX },
+                    { "destination": new_pubkey(), "amount": 250 },
+                ],
+            }),
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["data"]["instructions"].as_array().unwrap().len(), 2);
+        assert_eq!(body["data"]["errors"][0]["index"], 1);
+        assert_eq!(body["data"]["total_amount"], 350);
+    }
 
-    let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
-    println!("🚀 Server running at http://0.0.0.0:3000");
+    #[tokio::test]
+    async fn build_v0_rejects_lookup_table_indexes() {
+        let body = json!({
+            "secret": bs58::encode(Keypair::new().to_bytes()).into_string(),
+            "recent_blockhash": Hash::default().to_string(),
+            "instructions": [],
+            "lookup_tables": [{ "account": new_pubkey(), "writable_indexes": [0] }],
+        });
+        let request = axum::http::Request::post("/transaction/build-v0")
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = test_app().oneshot(request).await.unwrap();
 
-    serve(listener, app).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
 }